@@ -267,13 +267,13 @@ where
     })
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 enum PrevColor {
     Fg,
     Bg,
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
 /// Wrapper for `nu_ansi_term::Style` that supports referencing the previous style's foreground/background color.
 pub struct Style {
     style: nu_ansi_term::Style,
@@ -625,9 +625,10 @@ mod tests {
             pub switch_c: Switch,
         }
 
-        #[derive(Debug, PartialEq, Clone)]
+        #[derive(Debug, PartialEq, Clone, Default)]
         enum Switch {
             On,
+            #[default]
             Off,
         }
 
@@ -644,12 +645,6 @@ mod tests {
             }
         }
 
-        impl Default for Switch {
-            fn default() -> Self {
-                Self::Off
-            }
-        }
-
         let config = toml::toml! {
             switch_a = "on"
             switch_b = "any"
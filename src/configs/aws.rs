@@ -30,6 +30,10 @@ use std::collections::HashMap;
 ///
 /// When using [aws-sso-cli](https://github.com/synfinatic/aws-sso-cli) the profile
 /// is read from the `AWS_SSO_PROFILE` env var.
+///
+/// When using IAM Identity Center with `sso_session` or `sso_start_url`, the expiration
+/// date of the token cached in `~/.aws/sso/cache` is shown, following `source_profile`
+/// for profiles assuming a role.
 pub struct AwsConfig<'a> {
     /// The format for the module.
     pub format: &'a str,
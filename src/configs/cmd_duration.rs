@@ -15,6 +15,11 @@ pub struct CmdDurationConfig<'a> {
     pub disabled: bool,
     pub show_notifications: bool,
     pub min_time_to_notify: i64,
+    pub notification_backend: &'a str,
+    pub track_history: bool,
+    pub history_size: usize,
+    pub slower_ratio: f64,
+    pub slower_style: &'a str,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notification_timeout: Option<u32>,
@@ -30,6 +35,11 @@ impl Default for CmdDurationConfig<'_> {
             disabled: false,
             show_notifications: false,
             min_time_to_notify: 45_000,
+            notification_backend: "desktop",
+            track_history: false,
+            history_size: 20,
+            slower_ratio: 1.5,
+            slower_style: "red bold",
             notification_timeout: None,
         }
     }
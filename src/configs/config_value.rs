@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct ConfigValueConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_env: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_format: Option<&'a str>,
+    pub path: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<&'a str>,
+    pub aliases: HashMap<String, &'a str>,
+    pub disabled: bool,
+    pub description: &'a str,
+}
+
+impl Default for ConfigValueConfig<'_> {
+    fn default() -> Self {
+        Self {
+            format: "with [$symbol$value]($style) ",
+            symbol: "",
+            style: "black bold dimmed",
+            file: None,
+            file_env: None,
+            file_format: None,
+            path: "",
+            default: None,
+            aliases: HashMap::new(),
+            disabled: false,
+            description: "<config_value module>",
+        }
+    }
+}
@@ -11,6 +11,7 @@ pub struct ContainerConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
+    pub show_wsl: bool,
     pub disabled: bool,
 }
 
@@ -20,6 +21,7 @@ impl Default for ContainerConfig<'_> {
             format: "[$symbol \\[$name\\]]($style) ",
             symbol: "⬢",
             style: "red bold dimmed",
+            show_wsl: false,
             disabled: false,
         }
     }
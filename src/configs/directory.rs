@@ -12,8 +12,13 @@ use serde::{Deserialize, Serialize};
 pub struct DirectoryConfig<'a> {
     pub truncation_length: i64,
     pub truncate_to_repo: bool,
+    pub truncate_to_width: bool,
     pub substitutions: IndexMap<String, &'a str>,
+    pub regex_substitutions: IndexMap<String, &'a str>,
+    pub locations: IndexMap<String, DirectoryLocationConfig<'a>>,
+    pub root_markers: Vec<&'a str>,
     pub fish_style_pwd_dir_length: i64,
+    pub use_unique_prefix: bool,
     pub use_logical_path: bool,
     pub format: &'a str,
     pub repo_root_format: &'a str,
@@ -33,11 +38,24 @@ impl Default for DirectoryConfig<'_> {
         Self {
             truncation_length: 3,
             truncate_to_repo: true,
+            truncate_to_width: false,
             fish_style_pwd_dir_length: 0,
+            use_unique_prefix: false,
             use_logical_path: true,
             substitutions: IndexMap::new(),
-            format: "[$path]($style)[$read_only]($read_only_style) ",
-            repo_root_format: "[$before_root_path]($before_repo_root_style)[$repo_root]($repo_root_style)[$path]($style)[$read_only]($read_only_style) ",
+            regex_substitutions: IndexMap::new(),
+            locations: IndexMap::new(),
+            root_markers: vec![
+                ".git",
+                ".hg",
+                ".jj",
+                ".svn",
+                ".pijul",
+                "_darcs",
+                ".fslckout",
+            ],
+            format: "[$location]($location_style)[$path]($style)[$read_only]($read_only_style) ",
+            repo_root_format: "[$location]($location_style)[$before_root_path]($before_repo_root_style)[$repo_root]($repo_root_style)[$path]($style)[$read_only]($read_only_style) ",
             style: "cyan bold",
             repo_root_style: None,
             before_repo_root_style: None,
@@ -50,3 +68,15 @@ impl Default for DirectoryConfig<'_> {
         }
     }
 }
+
+#[derive(Clone, Deserialize, Serialize, Default)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct DirectoryLocationConfig<'a> {
+    pub symbol: &'a str,
+    pub style: Option<&'a str>,
+}
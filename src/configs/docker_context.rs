@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 #[serde(default)]
 pub struct DockerContextConfig<'a> {
     pub symbol: &'a str,
+    pub unreachable_symbol: &'a str,
     pub style: &'a str,
     pub format: &'a str,
     pub only_with_files: bool,
@@ -22,6 +23,7 @@ impl Default for DockerContextConfig<'_> {
     fn default() -> Self {
         Self {
             symbol: "🐳 ",
+            unreachable_symbol: "✗",
             style: "blue bold",
             format: "via [$symbol$context]($style) ",
             only_with_files: true,
@@ -27,8 +27,8 @@ impl Default for HelmConfig<'_> {
             style: "bold white",
             disabled: false,
             detect_extensions: vec![],
-            detect_files: vec!["helmfile.yaml", "Chart.yaml"],
-            detect_folders: vec![],
+            detect_files: vec!["helmfile.yaml", "helmfile.yaml.gotmpl", "Chart.yaml"],
+            detect_folders: vec!["helmfile.d"],
         }
     }
 }
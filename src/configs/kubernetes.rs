@@ -12,6 +12,7 @@ pub struct KubernetesConfig<'a> {
     pub symbol: &'a str,
     pub format: &'a str,
     pub style: &'a str,
+    pub expiration_symbol: &'a str,
     pub disabled: bool,
     pub context_aliases: HashMap<String, &'a str>,
     pub user_aliases: HashMap<String, &'a str>,
@@ -28,6 +29,7 @@ impl Default for KubernetesConfig<'_> {
             symbol: "☸ ",
             format: "[$symbol$context( \\($namespace\\))]($style) in ",
             style: "cyan bold",
+            expiration_symbol: "X",
             disabled: true,
             context_aliases: HashMap::new(),
             user_aliases: HashMap::new(),
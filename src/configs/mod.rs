@@ -13,6 +13,8 @@ pub mod cmake;
 pub mod cmd_duration;
 pub mod cobol;
 pub mod conda;
+pub mod config_value;
+pub mod consul;
 pub mod container;
 pub mod cpp;
 pub mod crystal;
@@ -65,6 +67,7 @@ pub mod netns;
 pub mod nim;
 pub mod nix_shell;
 pub mod nodejs;
+pub mod nomad;
 pub mod ocaml;
 pub mod odin;
 pub mod opa;
@@ -85,6 +88,7 @@ pub mod rlang;
 pub mod ruby;
 pub mod rust;
 pub mod scala;
+pub mod session;
 pub mod shell;
 pub mod shlvl;
 pub mod singularity;
@@ -100,6 +104,7 @@ pub mod typst;
 pub mod username;
 pub mod v;
 pub mod vagrant;
+pub mod vault;
 pub mod vcsh;
 pub mod xmake;
 pub mod zig;
@@ -144,6 +149,10 @@ pub struct FullConfig<'a> {
     #[serde(borrow)]
     conda: conda::CondaConfig<'a>,
     #[serde(borrow)]
+    config_value: IndexMap<String, config_value::ConfigValueConfig<'a>>,
+    #[serde(borrow)]
+    consul: consul::ConsulConfig<'a>,
+    #[serde(borrow)]
     container: container::ContainerConfig<'a>,
     #[serde(borrow)]
     cpp: cpp::CppConfig<'a>,
@@ -245,6 +254,8 @@ pub struct FullConfig<'a> {
     #[serde(borrow)]
     nodejs: nodejs::NodejsConfig<'a>,
     #[serde(borrow)]
+    nomad: nomad::NomadConfig<'a>,
+    #[serde(borrow)]
     ocaml: ocaml::OCamlConfig<'a>,
     #[serde(borrow)]
     odin: odin::OdinConfig<'a>,
@@ -285,6 +296,8 @@ pub struct FullConfig<'a> {
     #[serde(borrow)]
     scala: scala::ScalaConfig<'a>,
     #[serde(borrow)]
+    session: session::SessionConfig<'a>,
+    #[serde(borrow)]
     shell: shell::ShellConfig<'a>,
     #[serde(borrow)]
     shlvl: shlvl::ShLvlConfig<'a>,
@@ -311,6 +324,8 @@ pub struct FullConfig<'a> {
     #[serde(borrow)]
     vagrant: vagrant::VagrantConfig<'a>,
     #[serde(borrow)]
+    vault: vault::VaultConfig<'a>,
+    #[serde(borrow)]
     vcsh: vcsh::VcshConfig<'a>,
     #[serde(borrow)]
     vlang: v::VConfig<'a>,
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct NomadConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub disabled: bool,
+    pub address_aliases: HashMap<String, &'a str>,
+    pub namespace_aliases: HashMap<String, &'a str>,
+    pub region_aliases: HashMap<String, &'a str>,
+    pub detect_env_vars: Vec<&'a str>,
+}
+
+impl Default for NomadConfig<'_> {
+    fn default() -> Self {
+        Self {
+            format: "via [$symbol$address(/$namespace)( \\($region\\))]($style) ",
+            symbol: "🏕️  ",
+            style: "bold green",
+            disabled: false,
+            address_aliases: HashMap::new(),
+            namespace_aliases: HashMap::new(),
+            region_aliases: HashMap::new(),
+            detect_env_vars: vec![],
+        }
+    }
+}
@@ -11,6 +11,7 @@ pub struct PulumiConfig<'a> {
     pub format: &'a str,
     pub version_format: &'a str,
     pub symbol: &'a str,
+    pub missing_config_symbol: &'a str,
     pub style: &'a str,
     pub disabled: bool,
     pub search_upwards: bool,
@@ -22,6 +23,7 @@ impl Default for PulumiConfig<'_> {
             format: "via [$symbol($username@)$stack]($style) ",
             version_format: "v${raw}",
             symbol: " ",
+            missing_config_symbol: "⚠",
             style: "bold 5",
             disabled: false,
             search_upwards: true,
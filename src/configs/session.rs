@@ -0,0 +1,43 @@
+use indexmap::{IndexMap, indexmap};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct SessionConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub styles: IndexMap<String, &'a str>,
+    pub detect_multiplexers: bool,
+    pub disabled: bool,
+}
+
+impl<'a> SessionConfig<'a> {
+    pub fn get_style(&self, kind: &str) -> &'a str {
+        self.styles.get(kind).copied().unwrap_or(self.style)
+    }
+}
+
+impl Default for SessionConfig<'_> {
+    fn default() -> Self {
+        Self {
+            format: "[$symbol( $remote)( $multiplexer:$session)]($style) ",
+            symbol: "⇄",
+            style: "bold yellow",
+            styles: indexmap! {
+                "mosh".to_string() => "bold green",
+                "et".to_string() => "bold cyan",
+                "vscode".to_string() => "bold blue",
+                "jetbrains".to_string() => "bold purple",
+                "coder".to_string() => "bold bright-blue",
+            },
+            detect_multiplexers: true,
+            disabled: true,
+        }
+    }
+}
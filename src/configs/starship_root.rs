@@ -17,8 +17,17 @@ pub struct StarshipRootConfig {
     pub continuation_prompt: String,
     pub scan_timeout: u64,
     pub command_timeout: u64,
+    pub prompt_budget: u64,
+    pub module_cache_ttl: u64,
     pub add_newline: bool,
     pub follow_symlinks: bool,
+    pub slow_fs_types: Vec<String>,
+    pub slow_fs_disabled_modules: Vec<String>,
+    pub semantic_prompt: bool,
+    pub report_directory: bool,
+    pub title: TitleConfig,
+    pub status_bar: StatusBarConfig,
+    pub environment_tiers: IndexMap<String, EnvironmentTierConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub palette: Option<String>,
     pub palettes: HashMap<String, Palette>,
@@ -27,6 +36,43 @@ pub struct StarshipRootConfig {
 
 pub type Palette = HashMap<String, String>;
 
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct TitleConfig {
+    pub format: String,
+    pub running_format: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct StatusBarConfig {
+    pub modules: Vec<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct EnvironmentTierConfig {
+    pub patterns: Vec<String>,
+    pub modules: Vec<String>,
+    pub symbol: Option<String>,
+    pub style: Option<String>,
+}
+
 // List of default prompt order
 // NOTE: If this const value is changed then Default prompt order subheading inside
 // prompt heading of config docs needs to be updated according to changes made here.
@@ -34,6 +80,7 @@ pub const PROMPT_ORDER: &[&str] = &[
     "username",
     "hostname",
     "localip",
+    "session",
     "shlvl",
     "singularity",
     "kubernetes",
@@ -116,8 +163,12 @@ pub const PROMPT_ORDER: &[&str] = &[
     "gcloud",
     "openstack",
     "azure",
+    "vault",
+    "nomad",
+    "consul",
     "direnv",
     "env_var",
+    "config_value",
     "mise",
     "crystal",
     "custom",
@@ -147,8 +198,29 @@ impl Default for StarshipRootConfig {
             profiles: Default::default(),
             scan_timeout: 30,
             command_timeout: 500,
+            prompt_budget: 0,
+            module_cache_ttl: 0,
             add_newline: true,
             follow_symlinks: true,
+            slow_fs_types: [
+                "nfs",
+                "nfs4",
+                "cifs",
+                "smb3",
+                "smbfs",
+                "9p",
+                "afs",
+                "fuse.sshfs",
+                "fuse.rclone",
+            ]
+            .map(String::from)
+            .to_vec(),
+            slow_fs_disabled_modules: vec![],
+            semantic_prompt: false,
+            report_directory: false,
+            title: TitleConfig::default(),
+            status_bar: StatusBarConfig::default(),
+            environment_tiers: IndexMap::default(),
             palette: None,
             palettes: HashMap::default(),
         }
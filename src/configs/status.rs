@@ -1,3 +1,4 @@
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Deserialize, Serialize)]
@@ -22,6 +23,8 @@ pub struct StatusConfig<'a> {
     pub failure_style: Option<&'a str>,
     pub map_symbol: bool,
     pub recognize_signal_code: bool,
+    pub meanings: IndexMap<String, &'a str>,
+    pub success_codes: Vec<i32>,
     pub pipestatus: bool,
     pub pipestatus_separator: &'a str,
     pub pipestatus_format: &'a str,
@@ -45,6 +48,8 @@ impl Default for StatusConfig<'_> {
             failure_style: None,
             map_symbol: false,
             recognize_signal_code: true,
+            meanings: IndexMap::new(),
+            success_codes: vec![],
             pipestatus: false,
             pipestatus_separator: "|",
             pipestatus_format: "\\[$pipestatus\\] => [$symbol$common_meaning$signal_name$maybe_int]($style) ",
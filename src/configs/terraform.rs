@@ -11,11 +11,15 @@ pub struct TerraformConfig<'a> {
     pub format: &'a str,
     pub version_format: &'a str,
     pub symbol: &'a str,
+    pub plan_symbol: &'a str,
+    pub init_symbol: &'a str,
     pub style: &'a str,
     pub disabled: bool,
     pub detect_extensions: Vec<&'a str>,
     pub detect_files: Vec<&'a str>,
     pub detect_folders: Vec<&'a str>,
+    pub plan_extensions: Vec<&'a str>,
+    pub plan_files: Vec<&'a str>,
     pub commands: Vec<Vec<&'a str>>,
 }
 
@@ -25,11 +29,15 @@ impl Default for TerraformConfig<'_> {
             format: "via [$symbol$workspace]($style) ",
             version_format: "v${raw}",
             symbol: "💠 ",
+            plan_symbol: "📋",
+            init_symbol: "⚠",
             style: "bold 105",
             disabled: false,
             detect_extensions: vec!["tf", "tfplan", "tfstate"],
             detect_files: vec![],
             detect_folders: vec![".terraform"],
+            plan_extensions: vec!["tfplan"],
+            plan_files: vec!["tfplan", "plan.out"],
             commands: vec![
                 // terraform is usually `terraform`
                 vec!["terraform", "version"],
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "config-schema",
+    derive(schemars::JsonSchema),
+    schemars(deny_unknown_fields)
+)]
+#[serde(default)]
+pub struct VaultConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub expiration_symbol: &'a str,
+    pub token_ttl: u64,
+    pub disabled: bool,
+    pub address_aliases: HashMap<String, &'a str>,
+    pub namespace_aliases: HashMap<String, &'a str>,
+    pub detect_env_vars: Vec<&'a str>,
+}
+
+impl Default for VaultConfig<'_> {
+    fn default() -> Self {
+        Self {
+            format: "via [$symbol$address(/$namespace)]($style) ",
+            symbol: "🔐 ",
+            style: "bold yellow",
+            expiration_symbol: "X",
+            token_ttl: 0,
+            disabled: false,
+            address_aliases: HashMap::new(),
+            namespace_aliases: HashMap::new(),
+            detect_env_vars: vec![],
+        }
+    }
+}
@@ -13,6 +13,7 @@ use gix::{
     sec::{self as git_sec, trust::DefaultForLevel},
     state as git_state,
 };
+use std::cell::Cell;
 #[cfg(test)]
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -25,11 +26,17 @@ use std::num::ParseIntError;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::string::String;
-use std::sync::{Arc, OnceLock, mpsc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, OnceLock, mpsc};
 use std::thread;
 use std::time::{Duration, Instant};
 use terminal_size::terminal_size;
 
+thread_local! {
+    /// The `timeout` of the module that is being computed on this thread, if it sets one
+    static MODULE_TIMEOUT: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
 /// Context contains data or common methods that may be used by multiple modules.
 /// The data contained within Context will be relevant to this particular rendering
 /// of the prompt.
@@ -46,13 +53,16 @@ pub struct Context<'a> {
     pub logical_dir: PathBuf,
 
     /// A struct containing directory contents in a lookup-optimized format.
-    dir_contents: OnceLock<Result<DirContents, std::io::Error>>,
+    dir_contents: Arc<SharedWork<Result<DirContents, std::io::Error>>>,
 
     /// Properties to provide to modules.
     pub properties: Properties,
 
     /// Private field to store Git information for modules who need it
-    repo: OnceLock<Result<Repo, Box<gix::discover::Error>>>,
+    repo: Arc<SharedWork<Result<Repo, Box<gix::discover::Error>>>>,
+
+    /// The type of the file system the current directory is on
+    fs_type: OnceLock<Option<String>>,
 
     /// The shell the user is assumed to be running
     pub shell: Shell,
@@ -63,6 +73,9 @@ pub struct Context<'a> {
     /// Width of terminal, or zero if width cannot be detected.
     pub width: usize,
 
+    /// Columns left for the directory module once the rest of its prompt line is rendered.
+    directory_width: OnceLock<usize>,
+
     /// A `HashMap` of environment variable mocks
     pub env: Env<'a>,
 
@@ -80,6 +93,9 @@ pub struct Context<'a> {
     /// Starship root config
     pub root_config: StarshipRootConfig,
 
+    /// When computing the prompt started, to enforce `prompt_budget`
+    started: Instant,
+
     /// Avoid issues with unused lifetimes when features are disabled
     _marker: PhantomData<&'a ()>,
 }
@@ -168,11 +184,13 @@ impl<'a> Context<'a> {
             properties,
             current_dir,
             logical_dir,
-            dir_contents: OnceLock::new(),
-            repo: OnceLock::new(),
+            dir_contents: Arc::new(SharedWork::new()),
+            repo: Arc::new(SharedWork::new()),
+            fs_type: OnceLock::new(),
             shell,
             target,
             width,
+            directory_width: OnceLock::new(),
             env,
             #[cfg(test)]
             root_dir: tempfile::TempDir::new().unwrap(),
@@ -181,6 +199,7 @@ impl<'a> Context<'a> {
             #[cfg(feature = "battery")]
             battery_info_provider: &crate::modules::BatteryInfoProviderImpl,
             root_config,
+            started: Instant::now(),
             _marker: PhantomData,
         }
     }
@@ -228,14 +247,101 @@ impl<'a> Context<'a> {
         Module::new(name, desc, config)
     }
 
-    /// Check if `disabled` option of the module is true in configuration file.
+    /// Check if `disabled` option of the module is true in configuration file,
+    /// or if the module is disabled on the file system of the current directory.
     pub fn is_module_disabled_in_config(&self, name: &str) -> bool {
         let config = self.config.get_module_config(name);
 
         // If the segment has "disabled" set to "true", don't show it
         let disabled = config.and_then(|table| table.as_table()?.get("disabled")?.as_bool());
 
-        disabled == Some(true)
+        disabled == Some(true) || self.is_module_disabled_on_slow_fs(name)
+    }
+
+    /// The `timeout` set in the configuration of the module, in milliseconds.
+    /// Names like `custom.foo` refer to the nested `[custom.foo]` table.
+    fn module_timeout(&self, name: &str) -> Option<Duration> {
+        let path = name.split('.').collect::<Vec<_>>();
+        let timeout = self
+            .config
+            .get_config(&path)?
+            .as_table()?
+            .get("timeout")?
+            .as_integer()?;
+        u64::try_from(timeout).ok().map(Duration::from_millis)
+    }
+
+    /// Runs `f` on behalf of the module `name`, so that commands, git status and directory scans
+    /// started from this thread use the module's `timeout` instead of the global ones.
+    pub fn with_module_timeout<T>(&self, name: &str, f: impl FnOnce() -> T) -> T {
+        let previous = MODULE_TIMEOUT.replace(self.module_timeout(name));
+        let result = f();
+        MODULE_TIMEOUT.set(previous);
+        result
+    }
+
+    /// The time limit for commands, either the current module's `timeout` or `command_timeout`.
+    pub fn command_timeout(&self) -> Duration {
+        MODULE_TIMEOUT
+            .get()
+            .unwrap_or_else(|| Duration::from_millis(self.root_config.command_timeout))
+    }
+
+    /// How long the current module may wait for work it shares with other modules, like the
+    /// directory scan or git status. The work itself keeps running under the global limits.
+    pub fn shared_wait_timeout(&self) -> Option<Duration> {
+        MODULE_TIMEOUT.get()
+    }
+
+    /// Check if more time than `prompt_budget` has passed since computing the prompt started.
+    pub fn is_prompt_budget_exceeded(&self) -> bool {
+        let budget = self.root_config.prompt_budget;
+        budget > 0 && self.started.elapsed() >= Duration::from_millis(budget)
+    }
+
+    /// Check if the module is listed in `slow_fs_disabled_modules` and the current
+    /// directory is on one of the `slow_fs_types`.
+    pub fn is_module_disabled_on_slow_fs(&self, name: &str) -> bool {
+        let root_config = &self.root_config;
+        if !root_config
+            .slow_fs_disabled_modules
+            .iter()
+            .any(|module| module == name)
+        {
+            return false;
+        }
+
+        let disabled = self.is_on_slow_fs();
+        if disabled {
+            log::debug!(
+                "Skipping module {name:?} on slow file system {:?}",
+                self.fs_type()
+            );
+        }
+        disabled
+    }
+
+    /// Returns the type of the file system the current directory is on, e.g. `ext4` or `nfs4`.
+    pub fn fs_type(&self) -> Option<&str> {
+        self.fs_type
+            .get_or_init(|| self.current_dir.fs_type())
+            .as_deref()
+    }
+
+    /// Check if the current directory is on one of the `slow_fs_types`.
+    pub fn is_on_slow_fs(&self) -> bool {
+        self.fs_type().is_some_and(|fs_type| {
+            self.root_config
+                .slow_fs_types
+                .iter()
+                .any(|slow_fs_type| slow_fs_type == fs_type)
+        })
+    }
+
+    /// Overrides the detected file system type of the current directory.
+    #[cfg(test)]
+    pub fn set_fs_type(&mut self, fs_type: &str) {
+        self.fs_type = OnceLock::from(Some(fs_type.to_string()));
     }
 
     /// Returns true when a negated environment variable is defined in `env_vars` and is present
@@ -288,95 +394,46 @@ impl<'a> Context<'a> {
     }
 
     /// Will lazily get repo root and branch when a module requests it.
+    /// The current module gives up waiting after its `timeout`, without cancelling the discovery.
     pub fn get_repo(&self) -> Result<&Repo, &gix::discover::Error> {
-        self.repo
-            .get_or_init(|| -> Result<Repo, Box<gix::discover::Error>> {
-                // custom open options
-                let mut git_open_opts_map =
-                    git_sec::trust::Mapping::<gix::open::Options>::default();
-
-                // Load all the configuration as it affects aspects of the
-                // `git_status` and `git_metrics` modules.
-                let config = gix::open::permissions::Config {
-                    git_binary: true,
-                    system: true,
-                    git: true,
-                    user: true,
-                    env: true,
-                    includes: true,
-                };
-                // change options for config permissions without touching anything else
-                git_open_opts_map.reduced =
-                    git_open_opts_map
-                        .reduced
-                        .permissions(gix::open::Permissions {
-                            config,
-                            ..gix::open::Permissions::default_for_level(git_sec::Trust::Reduced)
-                        });
-                git_open_opts_map.full =
-                    git_open_opts_map.full.permissions(gix::open::Permissions {
-                        config,
-                        ..gix::open::Permissions::default_for_level(git_sec::Trust::Full)
-                    });
-
-                let shared_repo =
-                    match ThreadSafeRepository::discover_with_environment_overrides_opts(
-                        &self.current_dir,
-                        gix::discover::upwards::Options {
-                            match_ceiling_dir_or_error: false,
-                            ..Default::default()
-                        },
-                        git_open_opts_map,
-                    ) {
-                        Ok(repo) => repo,
-                        Err(e) => {
-                            log::debug!("Failed to find git repo: {e}");
-                            return Err(Box::new(e));
-                        }
-                    };
+        static TIMED_OUT: LazyLock<gix::discover::Error> = LazyLock::new(|| {
+            gix::discover::upwards::Error::CurrentDir(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "timed out waiting for the git repository",
+            ))
+            .into()
+        });
 
-                let repository = shared_repo.to_thread_local();
-                log::trace!(
-                    "Found git repo: {repository:?}, (trust: {:?})",
-                    repository.git_dir_trust()
-                );
-
-                let branch = get_current_branch(&repository);
-                let remote =
-                    get_remote_repository_info(&repository, branch.as_ref().map(AsRef::as_ref));
-                let path = repository.path().to_path_buf();
-
-                let fs_monitor_value_is_true = repository
-                    .config_snapshot()
-                    .boolean("core.fsmonitor")
-                    .unwrap_or(false);
-
-                Ok(Repo {
-                    repo: shared_repo,
-                    branch: branch.map(|b| b.shorten().to_string()),
-                    workdir: repository.workdir().map(PathBuf::from),
-                    path,
-                    state: repository.state(),
-                    remote,
-                    fs_monitor_value_is_true,
-                    kind: repository.kind(),
-                })
-            })
-            .as_ref()
-            .map_err(std::convert::AsRef::as_ref)
+        let current_dir = self.current_dir.clone();
+        match self.repo.get(self.shared_wait_timeout(), move || {
+            discover_repo(&current_dir)
+        }) {
+            Some(repo) => repo.as_ref().map_err(AsRef::as_ref),
+            None => {
+                log::debug!("Timed out waiting for the git repository");
+                Err(&TIMED_OUT)
+            }
+        }
     }
 
+    /// The contents of the current directory, scanned once within `scan_timeout`.
+    /// The current module gives up waiting after its `timeout`, without truncating the scan.
     pub fn dir_contents(&self) -> Result<&DirContents, &std::io::Error> {
+        static TIMED_OUT: LazyLock<std::io::Error> = LazyLock::new(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "timed out waiting for the directory scan",
+            )
+        });
+
+        let current_dir = self.current_dir.clone();
+        let scan_timeout = Duration::from_millis(self.root_config.scan_timeout);
+        let follow_symlinks = self.root_config.follow_symlinks;
         self.dir_contents
-            .get_or_init(|| {
-                let timeout = self.root_config.scan_timeout;
-                DirContents::from_path_with_timeout(
-                    &self.current_dir,
-                    Duration::from_millis(timeout),
-                    self.root_config.follow_symlinks,
-                )
+            .get(self.shared_wait_timeout(), move || {
+                DirContents::from_path_with_timeout(&current_dir, scan_timeout, follow_symlinks)
             })
-            .as_ref()
+            .map_or(Err(&TIMED_OUT), Result::as_ref)
     }
 
     fn get_shell() -> Shell {
@@ -397,6 +454,22 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Returns the directory in which modules may cache data between prompts.
+    pub fn get_cache_dir(&self) -> PathBuf {
+        get_cache_dir(&self.env)
+    }
+
+    /// Returns the number of columns the directory module may use,
+    /// if the prompt has measured the other modules on its line.
+    pub fn directory_width(&self) -> Option<usize> {
+        self.directory_width.get().copied()
+    }
+
+    /// Records the number of columns the directory module may use.
+    pub fn set_directory_width(&self, width: usize) {
+        let _ = self.directory_width.set(width);
+    }
+
     // TODO: This should be used directly by clap parse
     pub fn get_cmd_duration(&self) -> Option<u128> {
         self.properties
@@ -427,10 +500,7 @@ impl<'a> Context<'a> {
         }
         let mut cmd = create_command(cmd).ok()?;
         cmd.args(args).current_dir(&self.current_dir);
-        exec_timeout(
-            &mut cmd,
-            Duration::from_millis(self.root_config.command_timeout),
-        )
+        exec_timeout(&mut cmd, self.command_timeout())
     }
 
     /// Attempt to execute several commands with `exec_cmd`, return the results of the first that works
@@ -472,6 +542,19 @@ fn home_dir(env: &Env) -> Option<PathBuf> {
     utils::home_dir()
 }
 
+/// Returns `STARSHIP_CACHE`, or the `starship` directory in the user's cache directory.
+pub fn get_cache_dir(env: &Env) -> PathBuf {
+    env.get_env_os("STARSHIP_CACHE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            home_dir(env)
+                .map(|home| home.join(".cache"))
+                .or_else(dirs::cache_dir)
+                .unwrap_or_else(std::env::temp_dir)
+                .join("starship")
+        })
+}
+
 fn get_config_path_os(env: &Env) -> Option<OsString> {
     if let Some(config_path) = env.get_env_os("STARSHIP_CONFIG") {
         return Some(config_path);
@@ -479,6 +562,74 @@ fn get_config_path_os(env: &Env) -> Option<OsString> {
     Some(home_dir(env)?.join(".config").join("starship.toml").into())
 }
 
+/// Work that is done once and shared by all modules, each of which may stop waiting for it
+/// after its own `timeout` while the work goes on for the others.
+struct SharedWork<T> {
+    value: OnceLock<T>,
+    started: AtomicBool,
+    done: parking_lot::Mutex<bool>,
+    finished: parking_lot::Condvar,
+}
+
+impl<T: Send + Sync + 'static> SharedWork<T> {
+    const fn new() -> Self {
+        Self {
+            value: OnceLock::new(),
+            started: AtomicBool::new(false),
+            done: parking_lot::Mutex::new(false),
+            finished: parking_lot::Condvar::new(),
+        }
+    }
+
+    /// Returns the result of `work`, starting it if no module has yet, or `None` if it
+    /// didn't finish within `timeout`.
+    fn get(
+        self: &Arc<Self>,
+        timeout: Option<Duration>,
+        work: impl FnOnce() -> T + Send + 'static,
+    ) -> Option<&T> {
+        if let Some(value) = self.value.get() {
+            return Some(value);
+        }
+        if !self.started.swap(true, Ordering::AcqRel) {
+            // A panicking `work` still has to release the modules waiting for it
+            let run = move |shared: &Self| {
+                let value = std::panic::catch_unwind(std::panic::AssertUnwindSafe(work));
+                shared.finish(value.ok());
+            };
+            match timeout {
+                // Without a time limit there is nothing to give up on, so work on this thread
+                None => run(self),
+                Some(_) => {
+                    let shared = Arc::clone(self);
+                    thread::Builder::new()
+                        .name("starship shared work".into())
+                        .spawn(move || run(&shared))
+                        .expect("should be able to spawn shared work thread");
+                }
+            }
+        }
+
+        let mut done = self.done.lock();
+        match timeout {
+            None => self.finished.wait_while(&mut done, |done| !*done),
+            Some(timeout) => {
+                self.finished
+                    .wait_while_for(&mut done, |done| !*done, timeout);
+            }
+        }
+        self.value.get()
+    }
+
+    fn finish(&self, value: Option<T>) {
+        if let Some(value) = value {
+            let _ = self.value.set(value);
+        }
+        *self.done.lock() = true;
+        self.finished.notify_all();
+    }
+}
+
 #[derive(Debug)]
 pub struct DirContents {
     // HashSet of all files, no folders, relative to the base directory given at construction.
@@ -518,9 +669,9 @@ impl DirContents {
 
         {
             let worker = move || {
-                let enumerated_dir = fs::read_dir(base).unwrap().enumerate();
-                let _ = enumerated_dir
-                    .filter_map(|(_, entry)| entry.ok())
+                let dir_entries = fs::read_dir(base).unwrap();
+                let _ = dir_entries
+                    .filter_map(Result::ok)
                     .try_for_each(|entry| tx.send(entry));
             };
 
@@ -736,10 +887,7 @@ impl Repo {
         command.args(git_args);
         log::trace!("Executing git command: {command:?}");
 
-        exec_timeout(
-            &mut command,
-            Duration::from_millis(context.root_config.command_timeout),
-        )
+        exec_timeout(&mut command, context.command_timeout())
     }
 }
 
@@ -869,6 +1017,74 @@ impl<'a> ScanAncestors<'a> {
     }
 }
 
+fn discover_repo(current_dir: &Path) -> Result<Repo, Box<gix::discover::Error>> {
+    // custom open options
+    let mut git_open_opts_map = git_sec::trust::Mapping::<gix::open::Options>::default();
+
+    // Load all the configuration as it affects aspects of the
+    // `git_status` and `git_metrics` modules.
+    let config = gix::open::permissions::Config {
+        git_binary: true,
+        system: true,
+        git: true,
+        user: true,
+        env: true,
+        includes: true,
+    };
+    // change options for config permissions without touching anything else
+    git_open_opts_map.reduced = git_open_opts_map
+        .reduced
+        .permissions(gix::open::Permissions {
+            config,
+            ..gix::open::Permissions::default_for_level(git_sec::Trust::Reduced)
+        });
+    git_open_opts_map.full = git_open_opts_map.full.permissions(gix::open::Permissions {
+        config,
+        ..gix::open::Permissions::default_for_level(git_sec::Trust::Full)
+    });
+
+    let shared_repo = match ThreadSafeRepository::discover_with_environment_overrides_opts(
+        current_dir,
+        gix::discover::upwards::Options {
+            match_ceiling_dir_or_error: false,
+            ..Default::default()
+        },
+        git_open_opts_map,
+    ) {
+        Ok(repo) => repo,
+        Err(e) => {
+            log::debug!("Failed to find git repo: {e}");
+            return Err(Box::new(e));
+        }
+    };
+
+    let repository = shared_repo.to_thread_local();
+    log::trace!(
+        "Found git repo: {repository:?}, (trust: {:?})",
+        repository.git_dir_trust()
+    );
+
+    let branch = get_current_branch(&repository);
+    let remote = get_remote_repository_info(&repository, branch.as_ref().map(AsRef::as_ref));
+    let path = repository.path().to_path_buf();
+
+    let fs_monitor_value_is_true = repository
+        .config_snapshot()
+        .boolean("core.fsmonitor")
+        .unwrap_or(false);
+
+    Ok(Repo {
+        repo: shared_repo,
+        branch: branch.map(|b| b.shorten().to_string()),
+        workdir: repository.workdir().map(PathBuf::from),
+        path,
+        state: repository.state(),
+        remote,
+        fs_monitor_value_is_true,
+        kind: repository.kind(),
+    })
+}
+
 fn get_current_branch(repository: &Repository) -> Option<gix::refs::FullName> {
     repository.head_name().ok()?
 }
@@ -1301,6 +1517,117 @@ mod tests {
         assert_ne!(context.config.config, mod_context.config.config);
     }
 
+    #[test]
+    fn modules_disabled_on_slow_fs() {
+        let mut context = default_context().set_config(toml::toml! {
+            slow_fs_disabled_modules = ["git_status", "package"]
+        });
+        context.set_fs_type("nfs4");
+
+        assert!(context.is_module_disabled_in_config("git_status"));
+        assert!(context.is_module_disabled_in_config("package"));
+        assert!(!context.is_module_disabled_in_config("git_branch"));
+    }
+
+    #[test]
+    fn modules_enabled_on_other_fs() {
+        let mut context = default_context().set_config(toml::toml! {
+            slow_fs_types = ["fuse.sshfs"]
+            slow_fs_disabled_modules = ["git_status"]
+        });
+        context.set_fs_type("nfs4");
+
+        assert!(!context.is_on_slow_fs());
+        assert!(!context.is_module_disabled_in_config("git_status"));
+    }
+
+    #[test]
+    fn module_timeouts() {
+        let context = default_context().set_config(toml::toml! {
+            command_timeout = 500
+            scan_timeout = 30
+            [git_status]
+            timeout = 100
+            [custom.slow]
+            timeout = 2000
+        });
+
+        assert_eq!(context.command_timeout(), Duration::from_millis(500));
+        context.with_module_timeout("git_status", || {
+            assert_eq!(context.command_timeout(), Duration::from_millis(100));
+            assert_eq!(
+                context.shared_wait_timeout(),
+                Some(Duration::from_millis(100))
+            );
+        });
+        context.with_module_timeout("custom.slow", || {
+            assert_eq!(context.command_timeout(), Duration::from_millis(2000));
+        });
+        context.with_module_timeout("git_branch", || {
+            assert_eq!(context.command_timeout(), Duration::from_millis(500));
+            assert_eq!(context.shared_wait_timeout(), None);
+        });
+        assert_eq!(context.command_timeout(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn module_timeout_only_bounds_its_own_wait() {
+        let work = Arc::new(SharedWork::new());
+        let slow = || {
+            thread::sleep(Duration::from_millis(200));
+            "done"
+        };
+
+        assert_eq!(work.get(Some(Duration::from_millis(10)), slow), None);
+        // The work wasn't cancelled, and a module without a timeout gets its full result
+        assert_eq!(work.get(None, || "restarted"), Some(&"done"));
+        assert_eq!(
+            work.get(Some(Duration::ZERO), || "restarted"),
+            Some(&"done")
+        );
+    }
+
+    #[test]
+    fn panicking_shared_work_releases_waiters() {
+        let work = Arc::new(SharedWork::new());
+
+        let waiter = {
+            let work = Arc::clone(&work);
+            thread::spawn(move || {
+                // Wait for the panicking work below to have started
+                while !work.started.load(Ordering::Acquire) {
+                    thread::yield_now();
+                }
+                work.get(None, || "restarted").copied()
+            })
+        };
+        assert_eq!(work.get(None, || panic!("shared work failed")), None);
+        assert_eq!(waiter.join().unwrap(), None);
+        assert_eq!(work.get(None, || "restarted"), None);
+    }
+
+    #[test]
+    fn prompt_budget_skips_remaining_modules() {
+        let mut context = default_context().set_config(toml::toml! {
+            prompt_budget = 100
+            [env_var.FOO]
+            format = "$env_value"
+        });
+        context.env.insert("FOO", "foo".to_string());
+        assert!(!context.is_prompt_budget_exceeded());
+        assert!(modules::handle("env_var.FOO", &context).is_some());
+
+        context.started = Instant::now()
+            .checked_sub(Duration::from_millis(200))
+            .unwrap();
+        assert!(context.is_prompt_budget_exceeded());
+        assert!(modules::handle("env_var.FOO", &context).is_none());
+        assert!(modules::handle("character", &context).is_some());
+
+        context.root_config.prompt_budget = 0;
+        assert!(!context.is_prompt_budget_exceeded());
+    }
+
     #[cfg(windows)]
     #[test]
     fn strip_extended_path_prefix() {
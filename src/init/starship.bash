@@ -15,6 +15,18 @@
 # A way to set '$?', since bash does not allow assigning to '$?' directly
 function _starship_set_return() { return "${1:-0}"; }
 
+# Private sequence in the prompt, if the terminal title should be set while a command runs
+STARSHIP_RUNNING_TITLE_MARK=$'\e_starship:running-title\e\\'
+
+# Sets the terminal title while a command runs, if the prompt asked for it
+starship_title_preexec() {
+    local cmd
+    cmd=$(HISTTIMEFORMAT= builtin history 1)
+    # Strip the history number
+    [[ $cmd =~ ^[[:space:]]*[0-9]+[*[:space:]]+(.*)$ ]] && cmd=${BASH_REMATCH[1]}
+    ::STARSHIP:: title --command="$cmd"
+}
+
 # Will be run before *every* command (even ones in pipes!)
 starship_preexec() {
     # Save previous command's last argument, otherwise it will be set to "starship_preexec"
@@ -24,6 +36,10 @@ starship_preexec() {
     if [ "${STARSHIP_PREEXEC_READY:-}" = "true" ]; then
         STARSHIP_PREEXEC_READY=false
         STARSHIP_START_TIME=$(::STARSHIP:: time)
+        printf '%s' "${STARSHIP_PREEXEC_MARK-}"
+        if [[ ${STARSHIP_TITLE_HOOK-} ]]; then
+            starship_title_preexec
+        fi
     fi
 
     : "$PREV_LAST_ARG"
@@ -40,6 +56,11 @@ starship_precmd() {
         STARSHIP_PIPE_STATUS=("${BP_PIPESTATUS[@]}")
     fi
 
+    # Mark the end of the command output with its exit status (OSC 133;D), if its start was marked
+    if [[ ${STARSHIP_PREEXEC_MARK-} && ${STARSHIP_PREEXEC_READY-} != true ]]; then
+        printf '\e]133;D;%s\a' "$STARSHIP_CMD_STATUS"
+    fi
+
     # Due to a bug in certain Bash versions, any external process launched
     # inside $PROMPT_COMMAND will be reported by `jobs` as a background job:
     #
@@ -78,6 +99,19 @@ starship_precmd() {
         STARSHIP_START_TIME=""
     fi
     PS1="$(::STARSHIP:: prompt "${ARGS[@]}")"
+    # Mark the start of the command output (OSC 133;C) if the prompt emitted semantic prompt marks
+    if [[ $PS1 == *$'\e]133;A'* ]]; then
+        STARSHIP_PREEXEC_MARK=$'\e]133;C\a'
+    else
+        STARSHIP_PREEXEC_MARK=
+    fi
+    # Update the terminal title before the next command if the prompt asked for it
+    if [[ $PS1 == *"$STARSHIP_RUNNING_TITLE_MARK"* ]]; then
+        STARSHIP_TITLE_HOOK=1
+        PS1=${PS1//"$STARSHIP_RUNNING_TITLE_MARK"/}
+    else
+        STARSHIP_TITLE_HOOK=
+    fi
     if [[ ${BLE_ATTACHED-} ]]; then
         local nlns=${PS1//[!$'\n']}
         bleopt prompt_rps1="$nlns$(::STARSHIP:: prompt --right "${ARGS[@]}")"
@@ -105,7 +139,7 @@ else
         # In order to set STARSHIP_START_TIME use an arithmetic expansion that evaluates to 0
         # To avoid printing anything, use the return value in an ${var:offset:length} substring expansion
         # with offset and length evaluating to 0.
-        PS0='${STARSHIP_START_TIME:$((STARSHIP_START_TIME="$(starship_preexec_ps0)",STARSHIP_PREEXEC_READY=0,0)):0}'"${PS0-}"
+        PS0='${STARSHIP_START_TIME:$((STARSHIP_START_TIME="$(starship_preexec_ps0)",STARSHIP_PREEXEC_READY=0,0)):0}${STARSHIP_PREEXEC_MARK-}${STARSHIP_TITLE_HOOK:+$(starship_title_preexec)}'"${PS0-}"
     else
         # We want to avoid destroying an existing DEBUG hook. If we detect one, create
         # a new function that runs both the existing function AND our function, then
@@ -22,6 +22,7 @@ mod modules;
 pub mod print;
 mod segment;
 mod serde_utils;
+pub mod status_bar;
 mod utils;
 
 #[cfg(test)]
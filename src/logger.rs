@@ -1,4 +1,5 @@
-use crate::utils;
+use crate::context;
+use crate::context_env::Env;
 use log::{Level, LevelFilter, Metadata, Record};
 use nu_ansi_term::Color;
 use std::sync::OnceLock;
@@ -21,15 +22,7 @@ pub struct StarshipLogger {
 
 /// Returns the path to the log directory.
 pub fn get_log_dir() -> PathBuf {
-    env::var_os("STARSHIP_CACHE")
-        .map(PathBuf::from)
-        .unwrap_or_else(|| {
-            utils::home_dir()
-                .map(|home| home.join(".cache"))
-                .or_else(dirs::cache_dir)
-                .unwrap_or_else(std::env::temp_dir)
-                .join("starship")
-        })
+    context::get_cache_dir(&Env::default())
 }
 
 /// Deletes all log files in the log directory that were modified more than 24 hours ago.
@@ -10,7 +10,7 @@ use clap_complete::generate;
 use rand::Rng;
 use starship::context::{Context, Properties, Target};
 use starship::module::ALL_MODULES;
-use starship::{bug_report, configure, init, logger, num_rayon_threads, print, shadow};
+use starship::{bug_report, configure, init, logger, num_rayon_threads, print, shadow, status_bar};
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -128,9 +128,26 @@ enum Commands {
     },
     /// Generate random session key
     Session,
+    /// Prints the modules listed in `status_bar.modules` for a terminal multiplexer status bar
+    StatusBar {
+        /// The output format
+        #[clap(long, value_enum, default_value_t = status_bar::StatusBarFormat::Tmux)]
+        format: status_bar::StatusBarFormat,
+        #[clap(flatten)]
+        properties: Properties,
+    },
     /// Prints time in milliseconds
     #[clap(hide = true)]
     Time,
+    /// Prints the terminal title while a command runs
+    #[clap(hide = true)]
+    Title {
+        /// The command line that is about to run
+        #[clap(long)]
+        command: Option<String>,
+        #[clap(flatten)]
+        properties: Properties,
+    },
     /// Prints timings of all active modules
     Timings(Properties),
     /// Toggle a given starship module
@@ -219,6 +236,13 @@ fn main() {
             };
             print::prompt(properties, target);
         }
+        Commands::StatusBar { format, properties } => {
+            status_bar::status_bar(properties, format);
+        }
+        Commands::Title {
+            command,
+            properties,
+        } => print::title(properties, command),
         Commands::Module {
             name,
             list,
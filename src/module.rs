@@ -19,6 +19,7 @@ pub const ALL_MODULES: &[&str] = &[
     "cmd_duration",
     "cobol",
     "conda",
+    "consul",
     "container",
     "cpp",
     "crystal",
@@ -69,6 +70,7 @@ pub const ALL_MODULES: &[&str] = &[
     "nim",
     "nix_shell",
     "nodejs",
+    "nomad",
     "ocaml",
     "odin",
     "opa",
@@ -89,6 +91,7 @@ pub const ALL_MODULES: &[&str] = &[
     "ruby",
     "rust",
     "scala",
+    "session",
     "shell",
     "shlvl",
     "singularity",
@@ -102,6 +105,7 @@ pub const ALL_MODULES: &[&str] = &[
     "typst",
     "username",
     "vagrant",
+    "vault",
     "vcsh",
     "vlang",
     "xmake",
@@ -1,24 +1,29 @@
 use std::cell::OnceCell;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use chrono::DateTime;
+use chrono::{DateTime, FixedOffset};
 use ini::Ini;
 use serde_json as json;
 use sha1::{Digest, Sha1};
 
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::aws::AwsConfig;
 use crate::formatter::StringFormatter;
-use crate::utils::render_time;
+use crate::utils::{self, render_time};
 
 type Profile = String;
 type Region = String;
 type AwsConfigFile = OnceCell<Option<Ini>>;
 type AwsCredsFile = OnceCell<Option<Ini>>;
 
+/// How many `source_profile`s are followed to find the SSO session of a profile assuming a role
+const MAX_SOURCE_PROFILES: usize = 8;
+
 fn get_credentials_file_path(context: &Context) -> Option<PathBuf> {
     context
         .get_env("AWS_SHARED_CREDENTIALS_FILE")
@@ -153,22 +158,105 @@ fn get_credentials_duration(
     } else {
         // get expiration from cached SSO credentials
         let config = get_config(context, aws_config)?;
-        let section = get_profile_config(config, aws_profile)?;
-        let start_url = section.get("sso_start_url")?;
-        // https://github.com/boto/botocore/blob/d7ff05fac5bf597246f9e9e3fac8f22d35b02e64/botocore/utils.py#L3350
-        let cache_key = crate::utils::encode_to_hex(&Sha1::digest(start_url.as_bytes()));
-        // https://github.com/aws/aws-cli/blob/b3421dcdd443db95999364e94266c0337b45cc43/awscli/customizations/sso/utils.py#L89
-        let mut sso_cred_path = context.get_home()?;
-        sso_cred_path.push(format!(".aws/sso/cache/{cache_key}.json"));
-        let sso_cred_json: json::Value =
-            json::from_str(&crate::utils::read_file(&sso_cred_path).ok()?).ok()?;
-        let expires_at = sso_cred_json.get("expiresAt")?.as_str();
-        DateTime::parse_from_rfc3339(expires_at?).ok()
+        let (session_name, start_url) = get_sso_session(config, aws_profile)?;
+        get_sso_token_expiration(context, session_name.unwrap_or(start_url), start_url)
     }?;
 
     Some(expiration_date.timestamp() - chrono::Local::now().timestamp())
 }
 
+/// Returns the name of the `sso-session` used by the profile, if any, and its start URL.
+/// Profiles assuming a role are followed to their `source_profile`.
+fn get_sso_session<'a>(
+    config: &'a Ini,
+    aws_profile: Option<&Profile>,
+) -> Option<(Option<&'a str>, &'a str)> {
+    let mut section = get_profile_config(config, aws_profile)?;
+    for _ in 0..MAX_SOURCE_PROFILES {
+        if let Some(session_name) = section.get("sso_session") {
+            let session = config.section(Some(format!("sso-session {session_name}")))?;
+            return Some((Some(session_name), session.get("sso_start_url")?));
+        }
+        if let Some(start_url) = section.get("sso_start_url") {
+            return Some((None, start_url));
+        }
+
+        let source_profile = section.get("source_profile")?;
+        // `[default]` is the only profile without the `profile` prefix
+        let source_profile = (source_profile != "default").then(|| source_profile.to_string());
+        section = get_profile_config(config, source_profile.as_ref())?;
+    }
+    None
+}
+
+/// Reads the expiration date of the SSO token cached for a session or start URL by the AWS CLI,
+/// falling back to tokens cached under other names by other tools logging in with the same URL
+fn get_sso_token_expiration(
+    context: &Context,
+    cache_key: &str,
+    start_url: &str,
+) -> Option<DateTime<FixedOffset>> {
+    let cache_dir = context.get_home()?.join(".aws/sso/cache");
+    let read_token =
+        |path: &Path| -> Option<json::Value> { json::from_str(&utils::read_file(path).ok()?).ok() };
+    let expires_at =
+        |token: &json::Value| DateTime::parse_from_rfc3339(token.get("expiresAt")?.as_str()?).ok();
+
+    // https://github.com/boto/botocore/blob/d7ff05fac5bf597246f9e9e3fac8f22d35b02e64/botocore/utils.py#L3350
+    let cache_key = utils::encode_to_hex(&Sha1::digest(cache_key.as_bytes()));
+    // https://github.com/aws/aws-cli/blob/b3421dcdd443db95999364e94266c0337b45cc43/awscli/customizations/sso/utils.py#L89
+    if let Some(token) = read_token(&cache_dir.join(format!("{cache_key}.json"))) {
+        return expires_at(&token);
+    }
+
+    fs::read_dir(&cache_dir)
+        .ok()?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "json" {
+                return None;
+            }
+            let token = read_token(&path)?;
+            // The client registrations cached alongside the tokens expire as well
+            token.get("accessToken")?;
+            if token.get("startUrl")?.as_str()? != start_url {
+                return None;
+            }
+            expires_at(&token)
+        })
+        .max()
+}
+
+/// Returns the account id and role name of the profile, either those of IAM Identity Center or
+/// those of the role it assumes
+fn get_account_and_role(
+    context: &Context,
+    aws_profile: Option<&Profile>,
+    aws_config: &AwsConfigFile,
+) -> (Option<String>, Option<String>) {
+    let Some(section) =
+        get_config(context, aws_config).and_then(|config| get_profile_config(config, aws_profile))
+    else {
+        return (None, None);
+    };
+
+    if let Some(role_arn) = section.get("role_arn") {
+        // arn:aws:iam::123456789012:role/path/name
+        let mut parts = role_arn.splitn(6, ':').skip(4);
+        let account_id = parts.next().filter(|id| !id.is_empty());
+        let role = parts
+            .next()
+            .and_then(|resource| resource.strip_prefix("role/"))
+            .and_then(|path| path.rsplit('/').next());
+        return (account_id.map(String::from), role.map(String::from));
+    }
+
+    (
+        section.get("sso_account_id").map(String::from),
+        section.get("sso_role_name").map(String::from),
+    )
+}
+
 fn alias_name(name: Option<String>, aliases: &HashMap<String, &str>) -> Option<String> {
     name.as_ref()
         .and_then(|n| aliases.get(n))
@@ -287,6 +375,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         )
     };
 
+    let (account_id, role) = get_account_and_role(context, aws_profile.as_ref(), &aws_config);
+
+    let tier = Tier::classify(
+        context,
+        "aws",
+        &[aws_profile.as_deref(), account_id.as_deref()],
+    );
+
     let mapped_region = alias_name(aws_region, &config.region_aliases);
 
     let mapped_profile = alias_name(aws_profile, &config.profile_aliases);
@@ -294,17 +390,20 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
-                "symbol" => Some(config.symbol),
+                "symbol" => Some(tier.symbol_or(config.symbol)),
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
+                "style" => Some(Ok(tier.style_or(config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
-                "profile" => mapped_profile.as_ref().map(Ok),
-                "region" => mapped_region.as_ref().map(Ok),
-                "duration" => duration.as_ref().map(Ok),
+                "profile" => mapped_profile.as_deref().map(Ok),
+                "region" => mapped_region.as_deref().map(Ok),
+                "duration" => duration.as_deref().map(Ok),
+                "account_id" => account_id.as_deref().map(Ok),
+                "role" => role.as_deref().map(Ok),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name)),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -1116,6 +1215,92 @@ sso_registration_scopes = sso:account:access
         dir.close()
     }
 
+    #[test]
+    fn sso_session_expiration_with_account_and_role() -> io::Result<()> {
+        let (module_renderer, dir) = ModuleRenderer::new_with_home("aws")?;
+        std::fs::create_dir_all(dir.path().join(".aws/sso/cache"))?;
+
+        std::fs::write(
+            dir.path().join(".aws/config"),
+            "[profile astronauts]
+sso_session = my-sso
+sso_account_id = 123456789011
+sso_role_name = readOnly
+region = us-west-2
+
+[sso-session my-sso]
+sso_region = us-east-1
+sso_start_url = https://starship.rs/sso
+",
+        )?;
+        std::fs::write(
+            dir.path()
+                // SHA-1 of "my-sso"
+                .join(".aws/sso/cache/0ad374308c5a4e22f723adf10145eafad7c4031c.json"),
+            r#"{"startUrl": "https://starship.rs/sso", "expiresAt": "2020-01-01T00:00:00Z"}"#,
+        )?;
+
+        let actual = module_renderer
+            .env("AWS_PROFILE", "astronauts")
+            .config(toml::toml! {
+                [aws]
+                format = "on [$symbol$account_id/$role( \\[$duration\\])]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  123456789011/readOnly [X]")
+        ));
+
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn assumed_role_with_sso_source_profile() -> io::Result<()> {
+        let (module_renderer, dir) = ModuleRenderer::new_with_home("aws")?;
+        std::fs::create_dir_all(dir.path().join(".aws/sso/cache"))?;
+
+        std::fs::write(
+            dir.path().join(".aws/config"),
+            "[profile admin]
+role_arn = arn:aws:iam::210987654321:role/ops/Admin
+source_profile = sso-base
+
+[profile sso-base]
+sso_start_url = https://starship.rs/sso
+sso_region = us-east-1
+",
+        )?;
+        // A token cached by another tool, next to a client registration which expires later
+        std::fs::write(
+            dir.path().join(".aws/sso/cache/other-tool.json"),
+            r#"{"startUrl": "https://starship.rs/sso", "accessToken": "token", "expiresAt": "2020-01-01T00:00:00Z"}"#,
+        )?;
+        std::fs::write(
+            dir.path().join(".aws/sso/cache/registration.json"),
+            r#"{"startUrl": "https://starship.rs/sso", "clientId": "client", "expiresAt": "2100-01-01T00:00:00Z"}"#,
+        )?;
+
+        let actual = module_renderer
+            .env("AWS_PROFILE", "admin")
+            .env("AWS_REGION", "eu-west-1")
+            .config(toml::toml! {
+                [aws]
+                format = "on [$symbol$profile \\($account_id:$role\\)( \\[$duration\\])]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow
+                .bold()
+                .paint("☁️  admin (210987654321:Admin) [X]")
+        ));
+
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn access_key_env_var_set() {
         let actual = ModuleRenderer::new("aws")
@@ -1245,4 +1430,38 @@ source_profile = starship
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    #[test]
+    fn profile_classified_as_tier() {
+        let config = toml::toml! {
+            [environment_tiers.prod]
+            patterns = [".*-prod"]
+            style = "bold red"
+            symbol = "⚠ "
+            [aws]
+            format = "on [$symbol$profile( \\[$tier\\])]($style) "
+        };
+
+        let actual = ModuleRenderer::new("aws")
+            .env("AWS_PROFILE", "payments-prod")
+            .env("AWS_ACCESS_KEY_ID", "dummy")
+            .config(config.clone())
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Red.bold().paint("⚠ payments-prod [prod]")
+        ));
+        assert_eq!(expected, actual);
+
+        let actual = ModuleRenderer::new("aws")
+            .env("AWS_PROFILE", "payments-dev")
+            .env("AWS_ACCESS_KEY_ID", "dummy")
+            .config(config)
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  payments-dev")
+        ));
+        assert_eq!(expected, actual);
+    }
 }
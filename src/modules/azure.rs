@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::azure::AzureConfig;
 use crate::formatter::StringFormatter;
+use crate::utils;
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -18,14 +21,37 @@ struct AzureProfile {
 #[derive(Serialize, Deserialize, Clone)]
 struct User {
     name: String,
+    #[serde(default, rename = "type")]
+    kind: String,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct Subscription {
+    #[serde(default)]
+    id: String,
     name: String,
     user: User,
     is_default: bool,
+    #[serde(default)]
+    tenant_id: String,
+    #[serde(default)]
+    tenant_display_name: Option<String>,
+}
+
+/// An environment of the Azure Developer CLI (`azd`)
+struct AzdEnvironment {
+    name: String,
+    values: HashMap<String, String>,
+}
+
+/// The subscription shown by the module and the identity used with it
+struct AzureAccount {
+    subscription: String,
+    subscription_id: Option<String>,
+    username: Option<String>,
+    tenant: Option<String>,
+    login_type: Option<&'static str>,
 }
 
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
@@ -36,32 +62,40 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let subscription: Option<Subscription> = get_azure_profile_info(context);
-
-    if subscription.is_none() {
-        log::info!("Could not find Subscriptions in azureProfile.json");
-        return None;
-    }
+    let azd_environment = get_azd_environment(context);
+    let account = get_azure_account(context, azd_environment.as_ref())?;
 
-    let subscription = subscription.unwrap();
+    let subscription = config
+        .subscription_aliases
+        .get(&account.subscription)
+        .copied()
+        .unwrap_or(&account.subscription);
+    let tier = Tier::classify(
+        context,
+        "azure",
+        &[
+            Some(&account.subscription),
+            account.subscription_id.as_deref(),
+        ],
+    );
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
-                "symbol" => Some(config.symbol),
+                "symbol" => Some(tier.symbol_or(config.symbol)),
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
+                "style" => Some(Ok(tier.style_or(config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
-                "subscription" => Some(Ok(config
-                    .subscription_aliases
-                    .get(&subscription.name)
-                    .copied()
-                    .unwrap_or(&subscription.name))),
-                "username" => Some(Ok(&subscription.user.name)),
+                "subscription" => Some(Ok(subscription)),
+                "username" => account.username.as_deref().map(Ok),
+                "tenant" => account.tenant.as_deref().map(Ok),
+                "environment" => azd_environment.as_ref().map(|env| Ok(env.name.as_str())),
+                "login_type" => account.login_type.map(Ok),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name)),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -78,7 +112,95 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_azure_profile_info(context: &Context) -> Option<Subscription> {
+/// Finds the subscription that Terraform, the Azure SDKs or `azd` would use, falling back
+/// to the default subscription of the Azure CLI
+fn get_azure_account(
+    context: &Context,
+    azd_environment: Option<&AzdEnvironment>,
+) -> Option<AzureAccount> {
+    // `ARM_SUBSCRIPTION_ID` is set for Terraform specifically, so it wins over the more
+    // general `AZURE_SUBSCRIPTION_ID`, like the other `ARM_` variables below
+    let env_subscription_id = ["ARM_SUBSCRIPTION_ID", "AZURE_SUBSCRIPTION_ID"]
+        .into_iter()
+        .find_map(|name| context.get_env(name));
+    let from_env = env_subscription_id.is_some();
+    let subscription_id = env_subscription_id.or_else(|| {
+        azd_environment?
+            .values
+            .get("AZURE_SUBSCRIPTION_ID")
+            .cloned()
+    });
+
+    let Some(subscription) = get_azure_profile_info(context, subscription_id.as_deref()) else {
+        log::info!("Could not find Subscriptions in azureProfile.json");
+        // The subscription may not be known to the Azure CLI, e.g. in pipelines
+        let env_login = get_env_login_type(context);
+        return subscription_id.map(|id| AzureAccount {
+            subscription: id.clone(),
+            subscription_id: Some(id),
+            username: env_login
+                .as_ref()
+                .and_then(|(_, client_id)| client_id.clone()),
+            tenant: get_env_tenant(context, azd_environment),
+            login_type: env_login.map(|(login_type, _)| login_type),
+        });
+    };
+
+    let mut account = AzureAccount {
+        login_type: match subscription.user.kind.as_str() {
+            "user" => Some("user"),
+            "servicePrincipal" => Some(match subscription.user.name.as_str() {
+                "systemAssignedIdentity" | "userAssignedIdentity" => "managed_identity",
+                _ => "service_principal",
+            }),
+            _ => None,
+        },
+        tenant: subscription
+            .tenant_display_name
+            .filter(|name| !name.is_empty())
+            .or_else(|| Some(subscription.tenant_id).filter(|id| !id.is_empty())),
+        subscription_id: Some(subscription.id).filter(|id| !id.is_empty()),
+        username: Some(subscription.user.name),
+        subscription: subscription.name,
+    };
+
+    // Credentials in the environment take precedence over the login of the Azure CLI
+    if from_env {
+        if let Some((login_type, client_id)) = get_env_login_type(context) {
+            account.login_type = Some(login_type);
+            account.username = client_id.or(account.username);
+        }
+        account.tenant = get_env_tenant(context, None).or(account.tenant);
+    }
+
+    Some(account)
+}
+
+/// Returns the login type and client id of the credentials set in the environment, e.g. for Terraform
+fn get_env_login_type(context: &Context) -> Option<(&'static str, Option<String>)> {
+    let client_id = ["ARM_CLIENT_ID", "AZURE_CLIENT_ID"]
+        .into_iter()
+        .find_map(|name| context.get_env(name));
+    if context
+        .get_env("ARM_USE_MSI")
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"))
+    {
+        return Some(("managed_identity", client_id));
+    }
+    client_id.map(|client_id| ("service_principal", Some(client_id)))
+}
+
+fn get_env_tenant(context: &Context, azd_environment: Option<&AzdEnvironment>) -> Option<String> {
+    ["ARM_TENANT_ID", "AZURE_TENANT_ID"]
+        .into_iter()
+        .find_map(|name| context.get_env(name))
+        .or_else(|| azd_environment?.values.get("AZURE_TENANT_ID").cloned())
+}
+
+fn get_azure_profile_info(
+    context: &Context,
+    subscription_id: Option<&str>,
+) -> Option<Subscription> {
     let mut config_path = get_config_file_location(context)?;
     config_path.push("azureProfile.json");
 
@@ -86,7 +208,54 @@ fn get_azure_profile_info(context: &Context) -> Option<Subscription> {
     azure_profile
         .subscriptions
         .into_iter()
-        .find(|s| s.is_default)
+        .find(|s| match subscription_id {
+            Some(id) => s.id.eq_ignore_ascii_case(id),
+            None => s.is_default,
+        })
+}
+
+/// Reads the selected environment of the `azd` project containing the current directory
+fn get_azd_environment(context: &Context) -> Option<AzdEnvironment> {
+    let project_dir = context
+        .current_dir
+        .ancestors()
+        .find(|dir| dir.join("azure.yaml").is_file() || dir.join("azure.yml").is_file())?;
+    let azure_dir = project_dir.join(".azure");
+
+    let name = context.get_env("AZURE_ENV_NAME").or_else(|| {
+        let config = utils::read_file(azure_dir.join("config.json")).ok()?;
+        let config: serde_json::Value = serde_json::from_str(&config).ok()?;
+        config
+            .get("defaultEnvironment")?
+            .as_str()
+            .map(ToOwned::to_owned)
+    })?;
+    let values = utils::read_file(azure_dir.join(&name).join(".env"))
+        .map(|content| parse_dotenv(&content))
+        .unwrap_or_default();
+
+    Some(AzdEnvironment { name, values })
+}
+
+/// Parses the `KEY="value"` lines of a dotenv file, as written by `azd`
+fn parse_dotenv(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, value) = line
+                .strip_prefix("export ")
+                .unwrap_or(line)
+                .split_once('=')?;
+            let value = value.trim();
+            let value = ['"', '\'']
+                .into_iter()
+                .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+                .unwrap_or(value);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
 }
 
 fn load_azure_profile(config_path: &PathBuf) -> Option<AzureProfile> {
@@ -780,6 +949,186 @@ mod tests {
         dir.close()
     }
 
+    const PROFILE_WITH_SERVICE_PRINCIPAL: &str = r#"{
+        "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+        "subscriptions": [
+          {
+            "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+            "name": "Subscription 1",
+            "user": { "name": "user@domain.com", "type": "user" },
+            "isDefault": true,
+            "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+            "tenantDisplayName": "Contoso"
+          },
+          {
+            "id": "D4442D26-EA6D-46C4-07CB-4F70B8AE5465",
+            "name": "Production",
+            "user": { "name": "8a1e4c52-deploy", "type": "servicePrincipal" },
+            "isDefault": false,
+            "tenantId": "a4e1bb4b-5330-2d50-339d-b9674d3a87bc"
+          }
+        ]
+      }"#;
+
+    #[test]
+    fn subscription_from_terraform_env_vars() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        generate_test_config(&dir, PROFILE_WITH_SERVICE_PRINCIPAL)?;
+
+        let render = |subscription_id: &str| {
+            ModuleRenderer::new("azure")
+                .config(toml::toml! {
+                    [azure]
+                    format = "[$subscription( $username)( $login_type)( @$tenant)]($style)"
+                    disabled = false
+                })
+                .env("AZURE_CONFIG_DIR", dir.path().to_string_lossy())
+                .env("ARM_SUBSCRIPTION_ID", subscription_id)
+                .env("ARM_CLIENT_ID", "0b3c9f1e-pipeline")
+                .env("ARM_TENANT_ID", "a4e1bb4b-5330-2d50-339d-b9674d3a87bc")
+                .collect()
+        };
+
+        let expected = Some(format!(
+            "{}",
+            Color::Blue.bold().paint(
+                "Production 0b3c9f1e-pipeline service_principal @a4e1bb4b-5330-2d50-339d-b9674d3a87bc"
+            )
+        ));
+        assert_eq!(render("d4442d26-ea6d-46c4-07cb-4f70b8ae5465"), expected);
+
+        // Subscriptions unknown to the Azure CLI are shown by their id
+        let expected = Some(format!(
+            "{}",
+            Color::Blue.bold().paint(
+                "0c5e8a3f-1111-2222-3333-444455556666 0b3c9f1e-pipeline service_principal @a4e1bb4b-5330-2d50-339d-b9674d3a87bc"
+            )
+        ));
+        assert_eq!(render("0c5e8a3f-1111-2222-3333-444455556666"), expected);
+
+        // `ARM_SUBSCRIPTION_ID` wins over `AZURE_SUBSCRIPTION_ID`
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [azure]
+                format = "[$subscription]($style)"
+                disabled = false
+            })
+            .env("AZURE_CONFIG_DIR", dir.path().to_string_lossy())
+            .env(
+                "AZURE_SUBSCRIPTION_ID",
+                "f3935dc9-92b5-9a93-da7b-42c325d86939",
+            )
+            .env(
+                "ARM_SUBSCRIPTION_ID",
+                "d4442d26-ea6d-46c4-07cb-4f70b8ae5465",
+            )
+            .collect();
+        let expected = Some(format!("{}", Color::Blue.bold().paint("Production")));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
+    #[test]
+    fn azd_environment() -> io::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+        generate_test_config(&config_dir, PROFILE_WITH_SERVICE_PRINCIPAL)?;
+
+        let project_dir = tempfile::tempdir()?;
+        File::create(project_dir.path().join("azure.yaml"))?.sync_all()?;
+        std::fs::create_dir_all(project_dir.path().join(".azure/prod"))?;
+        std::fs::create_dir_all(project_dir.path().join("src"))?;
+        std::fs::write(
+            project_dir.path().join(".azure/config.json"),
+            r#"{"version":1,"defaultEnvironment":"prod"}"#,
+        )?;
+        std::fs::write(
+            project_dir.path().join(".azure/prod/.env"),
+            "AZURE_ENV_NAME=\"prod\"\nAZURE_LOCATION=\"westeurope\"\nAZURE_SUBSCRIPTION_ID=\"d4442d26-ea6d-46c4-07cb-4f70b8ae5465\"\n",
+        )?;
+
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [azure]
+                format = "[$subscription \\($environment\\) $login_type @$tenant]($style)"
+                disabled = false
+            })
+            .path(project_dir.path().join("src"))
+            .env("AZURE_CONFIG_DIR", config_dir.path().to_string_lossy())
+            .collect();
+        let expected = Some(format!(
+            "{}",
+            Color::Blue
+                .bold()
+                .paint("Production (prod) service_principal @a4e1bb4b-5330-2d50-339d-b9674d3a87bc")
+        ));
+        assert_eq!(actual, expected);
+
+        // Outside of the project, the default subscription of the Azure CLI is shown
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [azure]
+                format = "[$subscription( \\($environment\\)) $login_type @$tenant]($style)"
+                disabled = false
+            })
+            .path(config_dir.path())
+            .env("AZURE_CONFIG_DIR", config_dir.path().to_string_lossy())
+            .collect();
+        let expected = Some(format!(
+            "{}",
+            Color::Blue.bold().paint("Subscription 1 user @Contoso")
+        ));
+        assert_eq!(actual, expected);
+
+        config_dir.close()?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn subscription_classified_as_tier() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Payments Production",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        generate_test_config(&dir, azure_profile_contents)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [environment_tiers.prod]
+                patterns = [".* Production"]
+                style = "bold red"
+                [azure]
+                disabled = false
+                format = "on [$symbol($subscription) \\[$tier\\]]($style) "
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Red.bold().paint("󰠅 Payments Production [prod]")
+        ));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
     fn save_string_to_file(
         dir: &TempDir,
         contents: &str,
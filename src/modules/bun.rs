@@ -1,10 +1,17 @@
 use super::{Context, Module, ModuleConfig};
 
+use super::utils::cache::CacheInputs;
 use crate::configs::bun::BunConfig;
 use crate::formatter::StringFormatter;
 use crate::formatter::VersionFormatter;
 use crate::utils::get_command_string_output;
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[],
+    env_vars: &[],
+};
+
 /// Creates a module with the current Bun version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("bun");
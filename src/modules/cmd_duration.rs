@@ -1,8 +1,15 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::cmd_duration::CmdDurationConfig;
 use crate::formatter::StringFormatter;
-use crate::utils::render_time;
+use crate::utils::{self, render_time};
 
 /// Outputs the time it took the last command to execute
 ///
@@ -23,18 +30,37 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let elapsed = context.get_cmd_duration()?;
     let config_min = config.min_time as u128;
 
+    // Every duration is recorded, so the history reflects the usual run and not only slow ones
+    let stats = if config.track_history {
+        let mut history = DurationHistory::load(context.get_cache_dir().join(HISTORY_FILE));
+        let session = context.get_env("STARSHIP_SESSION_KEY");
+        let stats = history.record(&context.logical_dir, elapsed, session, config.history_size);
+        history.save();
+        stats
+    } else {
+        None
+    };
+
     if elapsed < config_min {
         return None;
     }
+    let is_slower = stats
+        .as_ref()
+        .is_some_and(|stats| elapsed as f64 >= stats.median as f64 * config.slower_ratio);
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_style(|variable| match variable {
+                "style" if is_slower => Some(Ok(config.slower_style)),
                 "style" => Some(Ok(config.style)),
                 _ => None,
             })
             .map(|variable| match variable {
                 "duration" => Some(Ok(render_time(elapsed, config.show_milliseconds))),
+                "delta" => stats
+                    .as_ref()
+                    .map(|stats| Ok(stats.delta(elapsed, config.show_milliseconds))),
+                "percentile" => stats.as_ref().map(|stats| Ok(stats.percentile.to_string())),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -48,70 +74,271 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         }
     });
 
-    Some(undistract_me(module, &config, context, elapsed))
+    if config.show_notifications && config.min_time_to_notify as u128 <= elapsed {
+        notify(&config, context, elapsed);
+    }
+
+    Some(module)
 }
 
-#[cfg(not(feature = "notify"))]
-fn undistract_me<'a>(
-    module: Module<'a>,
-    _config: &CmdDurationConfig,
-    _context: &'a Context,
-    _elapsed: u128,
-) -> Module<'a> {
-    module
+/// Name of the file in the cache directory holding the `DurationHistory`
+const HISTORY_FILE: &str = "cmd_duration_history.json";
+
+/// Maximum number of directories kept in the `DurationHistory`
+const HISTORY_DIRECTORIES: usize = 256;
+
+/// Number of earlier durations needed before comparing against them
+const MIN_HISTORY: usize = 3;
+
+#[derive(Default, Deserialize, Serialize)]
+struct DirectoryHistory {
+    /// The shell session that recorded the latest duration
+    session: Option<String>,
+    durations: VecDeque<u128>,
 }
 
-#[cfg(feature = "notify")]
-fn undistract_me<'a>(
-    module: Module<'a>,
-    config: &CmdDurationConfig,
-    context: &'a Context,
-    elapsed: u128,
-) -> Module<'a> {
-    use notify_rust::{Notification, Timeout};
-    use nu_ansi_term::{AnsiStrings, unstyle};
+/// How the duration of the last command compares to the earlier ones
+#[derive(Debug, PartialEq)]
+struct DurationStats {
+    median: u128,
+    /// Percentage of the earlier durations that were shorter
+    percentile: usize,
+}
 
-    if config.show_notifications && config.min_time_to_notify as u128 <= elapsed {
-        if cfg!(target_os = "linux") {
-            let in_graphical_session = ["DISPLAY", "WAYLAND_DISPLAY", "MIR_SOCKET"]
-                .iter()
-                .find_map(|&var| context.get_env(var).filter(|val| !val.is_empty()))
-                .is_some();
-
-            if !in_graphical_session {
-                return module;
-            };
+impl DurationStats {
+    fn new(durations: &[u128], elapsed: u128) -> Option<Self> {
+        if durations.len() < MIN_HISTORY {
+            return None;
         }
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        let shorter = sorted
+            .iter()
+            .filter(|&&duration| duration < elapsed)
+            .count();
 
-        let body = format!(
-            "Command execution {}",
-            unstyle(&AnsiStrings(&module.ansi_strings()))
-        );
+        Some(Self {
+            median,
+            percentile: shorter * 100 / sorted.len(),
+        })
+    }
+
+    /// Renders the difference to the median, e.g. `+1m2s`
+    fn delta(&self, elapsed: u128, show_millis: bool) -> String {
+        if elapsed >= self.median {
+            format!("+{}", render_time(elapsed - self.median, show_millis))
+        } else {
+            format!("-{}", render_time(self.median - elapsed, show_millis))
+        }
+    }
+}
+
+/// The recent durations of all commands per directory, persisted between prompts
+struct DurationHistory {
+    path: PathBuf,
+    directories: IndexMap<PathBuf, DirectoryHistory>,
+    changed: bool,
+}
 
-        let timeout = match config.notification_timeout {
-            Some(v) => Timeout::Milliseconds(v),
-            None => Timeout::Default,
+impl DurationHistory {
+    fn load(path: PathBuf) -> Self {
+        let directories = fs::read(&path)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_slice(&content)
+                    .map_err(|e| log::debug!("Ignoring invalid duration history {path:?}: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        Self {
+            path,
+            directories,
+            changed: false,
+        }
+    }
+
+    /// Records the duration of the last command run in `dir`
+    /// and compares it against the earlier durations.
+    ///
+    /// The prompt may be rendered several times for the same command,
+    /// so a duration repeated by the same shell session is only recorded once.
+    fn record(
+        &mut self,
+        dir: &Path,
+        elapsed: u128,
+        session: Option<String>,
+        size: usize,
+    ) -> Option<DurationStats> {
+        let mut history = self.directories.shift_remove(dir).unwrap_or_default();
+        let is_repeat = history.session == session && history.durations.back() == Some(&elapsed);
+
+        let earlier = history.durations.make_contiguous();
+        let earlier = if is_repeat {
+            &earlier[..earlier.len() - 1]
+        } else {
+            earlier
         };
+        let stats = DurationStats::new(earlier, elapsed);
 
-        let mut notification = Notification::new();
-        notification
-            .summary("Command finished")
-            .body(&body)
-            .icon("utilities-terminal")
-            .timeout(timeout);
+        if !is_repeat {
+            history.durations.push_back(elapsed);
+            while history.durations.len() > size {
+                history.durations.pop_front();
+            }
+            history.session = session;
+            self.changed = true;
+        }
+        self.directories.insert(dir.to_path_buf(), history);
+        while self.directories.len() > HISTORY_DIRECTORIES {
+            self.directories.shift_remove_index(0);
+            self.changed = true;
+        }
+
+        stats
+    }
 
-        if let Err(err) = notification.show() {
-            log::trace!("Cannot show notification: {err}");
+    fn save(self) {
+        if !self.changed {
+            return;
+        }
+        let result = serde_json::to_vec(&self.directories)
+            .map_err(std::io::Error::from)
+            .and_then(|content| utils::replace_file(&self.path, &content));
+        if let Err(e) = result {
+            log::debug!("Unable to write duration history {:?}: {e}", self.path);
         }
     }
+}
+
+const NOTIFICATION_SUMMARY: &str = "Command finished";
+
+/// Sends a notification about the finished command using the configured backend
+fn notify(config: &CmdDurationConfig, context: &Context, elapsed: u128) {
+    let duration = render_time(elapsed, config.show_milliseconds);
+    let body = match context.properties.status_code.as_deref() {
+        Some(status) => format!("Command took {duration} (exit status {status})"),
+        None => format!("Command took {duration}"),
+    };
+
+    if config.notification_backend == "desktop" {
+        undistract_me(config, context, &body);
+        return;
+    }
+
+    let in_tmux = context.get_env("TMUX").is_some();
+    match notification_sequence(
+        config.notification_backend,
+        NOTIFICATION_SUMMARY,
+        &body,
+        in_tmux,
+    ) {
+        Some(sequence) => write_to_terminal(&sequence),
+        None => log::warn!(
+            "Unknown notification_backend in [cmd_duration]: {}",
+            config.notification_backend
+        ),
+    }
+}
+
+/// Builds the escape sequence asking the terminal to notify the user
+///
+/// Inside tmux, OSC sequences are wrapped in a passthrough sequence so they reach the
+/// outer terminal, which requires tmux's `allow-passthrough` option.
+fn notification_sequence(
+    backend: &str,
+    summary: &str,
+    body: &str,
+    in_tmux: bool,
+) -> Option<String> {
+    // Control characters would end the sequence early and `;` separates the OSC 777 fields
+    let sanitize = |text: &str| text.replace(|c: char| c.is_control() || c == ';', " ");
+    let osc = match backend {
+        "bell" => return Some("\x07".to_string()),
+        "osc9" => format!("\x1b]9;{}: {}\x1b\\", sanitize(summary), sanitize(body)),
+        "osc777" => format!(
+            "\x1b]777;notify;{};{}\x1b\\",
+            sanitize(summary),
+            sanitize(body)
+        ),
+        _ => return None,
+    };
+
+    if in_tmux {
+        Some(format!(
+            "\x1bPtmux;{}\x1b\\",
+            osc.replace('\x1b', "\x1b\x1b")
+        ))
+    } else {
+        Some(osc)
+    }
+}
+
+/// Writes directly to the controlling terminal, as the prompt itself is captured by the shell
+fn write_to_terminal(sequence: &str) {
+    use std::io::Write;
+
+    #[cfg(unix)]
+    let result = fs::OpenOptions::new()
+        .write(true)
+        .open("/dev/tty")
+        .and_then(|mut tty| tty.write_all(sequence.as_bytes()));
+    #[cfg(not(unix))]
+    let result = std::io::stderr().write_all(sequence.as_bytes());
 
-    module
+    if let Err(err) = result {
+        log::trace!("Cannot write notification to the terminal: {err}");
+    }
+}
+
+#[cfg(not(feature = "notify"))]
+fn undistract_me(_config: &CmdDurationConfig, _context: &Context, _body: &str) {}
+
+#[cfg(feature = "notify")]
+fn undistract_me(config: &CmdDurationConfig, context: &Context, body: &str) {
+    use notify_rust::{Notification, Timeout};
+
+    if cfg!(target_os = "linux") {
+        let in_graphical_session = ["DISPLAY", "WAYLAND_DISPLAY", "MIR_SOCKET"]
+            .iter()
+            .find_map(|&var| context.get_env(var).filter(|val| !val.is_empty()))
+            .is_some();
+
+        if !in_graphical_session {
+            return;
+        };
+    }
+
+    let timeout = match config.notification_timeout {
+        Some(v) => Timeout::Milliseconds(v),
+        None => Timeout::Default,
+    };
+
+    let mut notification = Notification::new();
+    notification
+        .summary(NOTIFICATION_SUMMARY)
+        .body(body)
+        .icon("utilities-terminal")
+        .timeout(timeout);
+
+    if let Err(err) = notification.show() {
+        log::trace!("Cannot show notification: {err}");
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::test::ModuleRenderer;
     use nu_ansi_term::Color;
+    use std::io;
+    use tempfile::TempDir;
 
     #[test]
     fn config_blank_duration_1s() {
@@ -188,4 +415,175 @@ mod tests {
         let expected = Some(format!("underwent {} ", Color::Yellow.bold().paint("5s")));
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn duration_stats() {
+        assert_eq!(DurationStats::new(&[1000, 2000], 5000), None);
+        assert_eq!(
+            DurationStats::new(&[3000, 1000, 2000], 2500),
+            Some(DurationStats {
+                median: 2000,
+                percentile: 66,
+            })
+        );
+        assert_eq!(
+            DurationStats::new(&[4000, 1000, 2000, 3000], 500),
+            Some(DurationStats {
+                median: 2500,
+                percentile: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn duration_stats_delta() {
+        let stats = DurationStats {
+            median: 60_000,
+            percentile: 50,
+        };
+        assert_eq!(stats.delta(75_000, false), "+15s");
+        assert_eq!(stats.delta(59_500, true), "-500ms");
+    }
+
+    #[test]
+    fn history_records_each_command_once() {
+        let mut history = DurationHistory {
+            path: PathBuf::new(),
+            directories: IndexMap::new(),
+            changed: false,
+        };
+        let dir = Path::new("/src");
+        let session = || Some("session".to_string());
+
+        for elapsed in [1000, 2000, 3000] {
+            assert_eq!(history.record(dir, elapsed, session(), 4), None);
+        }
+        let expected = Some(DurationStats {
+            median: 2000,
+            percentile: 100,
+        });
+        assert_eq!(history.record(dir, 8000, session(), 4), expected);
+        // The same prompt rendered again, e.g. for the right prompt
+        assert_eq!(history.record(dir, 8000, session(), 4), expected);
+        assert_eq!(
+            history.directories[dir].durations,
+            VecDeque::from([1000, 2000, 3000, 8000])
+        );
+        // Another shell finishing a command that took just as long
+        history.record(dir, 8000, Some("other".to_string()), 4);
+        assert_eq!(
+            history.directories[dir].durations,
+            VecDeque::from([2000, 3000, 8000, 8000])
+        );
+    }
+
+    #[test]
+    fn slower_than_usual() -> io::Result<()> {
+        let cache_dir = TempDir::new()?;
+        let render = |elapsed| {
+            ModuleRenderer::new("cmd_duration")
+                .config(toml::toml! {
+                    [cmd_duration]
+                    format = "took [$duration( $delta)( p$percentile)]($style) "
+                    track_history = true
+                })
+                .env("STARSHIP_CACHE", cache_dir.path().to_str().unwrap())
+                .path("/src")
+                .cmd_duration(elapsed)
+                .collect()
+        };
+
+        assert_eq!(
+            render(10_000),
+            Some(format!("took {} ", Color::Yellow.bold().paint("10s")))
+        );
+        render(12_000);
+        render(11_000);
+        assert_eq!(
+            render(12_000),
+            Some(format!(
+                "took {} ",
+                Color::Yellow.bold().paint("12s +1s p66")
+            ))
+        );
+        assert_eq!(
+            render(30_000),
+            Some(format!(
+                "took {} ",
+                Color::Red.bold().paint("30s +18s p100")
+            ))
+        );
+        assert!(cache_dir.path().join(HISTORY_FILE).exists());
+        cache_dir.close()
+    }
+
+    #[test]
+    fn history_includes_fast_commands() -> io::Result<()> {
+        let cache_dir = TempDir::new()?;
+        let render = |elapsed| {
+            ModuleRenderer::new("cmd_duration")
+                .config(toml::toml! {
+                    [cmd_duration]
+                    format = "took [$duration( $delta)]($style) "
+                    track_history = true
+                })
+                .env("STARSHIP_CACHE", cache_dir.path().to_str().unwrap())
+                .path("/src")
+                .cmd_duration(elapsed)
+                .collect()
+        };
+
+        assert_eq!(render(800), None);
+        assert_eq!(render(1_000), None);
+        assert_eq!(render(1_200), None);
+        assert_eq!(
+            render(4_000),
+            Some(format!("took {} ", Color::Red.bold().paint("4s +3s")))
+        );
+        cache_dir.close()
+    }
+
+    #[test]
+    fn no_history_by_default() {
+        let actual = ModuleRenderer::new("cmd_duration")
+            .config(toml::toml! {
+                [cmd_duration]
+                format = "took [$duration( $delta)]($style) "
+            })
+            .cmd_duration(5000)
+            .collect();
+
+        let expected = Some(format!("took {} ", Color::Yellow.bold().paint("5s")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn notification_sequences() {
+        let body = "Command took 1m5s (exit status 1)";
+        assert_eq!(
+            notification_sequence("bell", "Done", body, false),
+            Some("\x07".to_string())
+        );
+        assert_eq!(
+            notification_sequence("osc9", "Done", body, false),
+            Some("\x1b]9;Done: Command took 1m5s (exit status 1)\x1b\\".to_string())
+        );
+        assert_eq!(
+            notification_sequence("osc777", "Done", "a;b\x07c", false),
+            Some("\x1b]777;notify;Done;a b c\x1b\\".to_string())
+        );
+        assert_eq!(notification_sequence("smoke", "Done", body, false), None);
+    }
+
+    #[test]
+    fn notification_sequences_in_tmux() {
+        assert_eq!(
+            notification_sequence("osc9", "Done", "1s", true),
+            Some("\x1bPtmux;\x1b\x1b]9;Done: 1s\x1b\x1b\\\x1b\\".to_string())
+        );
+        assert_eq!(
+            notification_sequence("bell", "Done", "1s", true),
+            Some("\x07".to_string())
+        );
+    }
 }
@@ -0,0 +1,457 @@
+use std::path::{Path, PathBuf};
+
+use ini::Ini;
+use serde_json::{Map, Value};
+use yaml_rust2::{Yaml, YamlLoader};
+
+use super::{Context, Module};
+
+use crate::config::ModuleConfig;
+use crate::configs::config_value::ConfigValueConfig;
+use crate::formatter::StringFormatter;
+use crate::utils;
+
+/// Creates a module with a value read from a YAML, JSON, TOML or INI file
+///
+/// Will display the value if all of the following criteria are met:
+///     - `config_value.<name>.disabled` is absent or false
+///     - the file is found through `file_env` or `file`, and `path` points at a scalar in it
+///     - or `config_value.<name>.default` is defined
+pub fn module<'a>(name: &str, context: &'a Context) -> Option<Module<'a>> {
+    let toml_config = context.config.get_config(&["config_value", name]);
+    let config = ConfigValueConfig::try_load(toml_config);
+    // Note: Forward config if `Module` ends up needing `config`
+    let mut module = Module::new(format!("config_value.{name}"), config.description, None);
+    if config.disabled {
+        return None;
+    }
+
+    let Some(segments) = parse_path(config.path) else {
+        log::warn!(
+            "Invalid path `{}` in module `config_value.{name}`",
+            config.path
+        );
+        return None;
+    };
+
+    let value = find_file(context, &config)
+        .and_then(|file| load_file(&file, config.file_format))
+        .and_then(|document| scalar_to_string(lookup(&document, &document, &segments)?));
+    let value = value.or_else(|| config.default.map(ToString::to_string))?;
+    let value = config
+        .aliases
+        .get(&value)
+        .copied()
+        .unwrap_or(value.as_str());
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "value" => Some(Ok(value)),
+                _ => None,
+            })
+            .parse(None, Some(context))
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `config_value.{name}`:\n{error}");
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+/// Finds the file the value is read from.
+///
+/// The first path in the environment variable `file_env` takes precedence over `file`.
+/// A relative `file` is looked up in the current directory and its ancestors.
+fn find_file(context: &Context, config: &ConfigValueConfig) -> Option<PathBuf> {
+    if let Some(paths) = config.file_env.and_then(|var| context.get_env(var)) {
+        let path = std::env::split_paths(&paths).next()?;
+        return Some(context.current_dir.join(expand_home(context, &path)?));
+    }
+
+    let file = expand_home(context, Path::new(config.file?))?;
+    if file.is_absolute() {
+        return Some(file);
+    }
+
+    context
+        .current_dir
+        .ancestors()
+        .map(|dir| dir.join(&file))
+        .find(|path| path.is_file())
+}
+
+fn expand_home(context: &Context, path: &Path) -> Option<PathBuf> {
+    match path.strip_prefix("~") {
+        Ok(rest) => Some(context.get_home()?.join(rest)),
+        Err(_) => Some(path.to_path_buf()),
+    }
+}
+
+/// Parses the file into a JSON value, so values are looked up the same way in every format.
+///
+/// Without `file_format`, the format is chosen by the extension of the file, falling back to YAML.
+fn load_file(file: &Path, file_format: Option<&str>) -> Option<Value> {
+    let contents = utils::read_file(file)
+        .map_err(|error| log::debug!("Could not read {file:?}: {error}"))
+        .ok()?;
+
+    let extension = file.extension().and_then(|extension| extension.to_str());
+    let file_format = file_format.unwrap_or(match extension {
+        Some("json") => "json",
+        Some("toml") => "toml",
+        Some("ini" | "cfg" | "conf") => "ini",
+        _ => "yaml",
+    });
+
+    let document = match file_format {
+        "json" => serde_json::from_str(&contents).map_err(|error| error.to_string()),
+        "yaml" => YamlLoader::load_from_str(&contents)
+            .map(|documents| {
+                documents
+                    .into_iter()
+                    .next()
+                    .map_or(Value::Null, yaml_to_json)
+            })
+            .map_err(|error| error.to_string()),
+        "toml" => toml::from_str(&contents)
+            .map(toml_to_json)
+            .map_err(|error| error.to_string()),
+        "ini" => Ini::load_from_str(&contents)
+            .map(|ini| ini_to_json(&ini))
+            .map_err(|error| error.to_string()),
+        _ => {
+            log::warn!(
+                "Unknown file_format `{file_format}`, expected `yaml`, `json`, `toml` or `ini`"
+            );
+            return None;
+        }
+    };
+
+    document
+        .map_err(|error| log::debug!("Could not parse {file:?} as {file_format}: {error}"))
+        .ok()
+}
+
+fn yaml_to_json(yaml: Yaml) -> Value {
+    match yaml {
+        Yaml::String(value) | Yaml::Real(value) => Value::String(value),
+        Yaml::Integer(value) => Value::from(value),
+        Yaml::Boolean(value) => Value::Bool(value),
+        Yaml::Array(values) => Value::Array(values.into_iter().map(yaml_to_json).collect()),
+        Yaml::Hash(hash) => Value::Object(
+            hash.into_iter()
+                .filter_map(|(key, value)| {
+                    let key = match key {
+                        Yaml::String(key) | Yaml::Real(key) => key,
+                        Yaml::Integer(key) => key.to_string(),
+                        Yaml::Boolean(key) => key.to_string(),
+                        _ => return None,
+                    };
+                    Some((key, yaml_to_json(value)))
+                })
+                .collect(),
+        ),
+        Yaml::Alias(_) | Yaml::Null | Yaml::BadValue => Value::Null,
+    }
+}
+
+fn toml_to_json(toml: toml::Value) -> Value {
+    match toml {
+        toml::Value::String(value) => Value::String(value),
+        toml::Value::Integer(value) => Value::from(value),
+        toml::Value::Float(value) => Value::from(value),
+        toml::Value::Boolean(value) => Value::Bool(value),
+        toml::Value::Datetime(value) => Value::String(value.to_string()),
+        toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Keys outside of a section are at the top level, the others are nested in their section.
+fn ini_to_json(ini: &Ini) -> Value {
+    let mut document = Map::new();
+    for (section, properties) in ini {
+        let properties = properties
+            .iter()
+            .map(|(key, value)| (key.to_string(), Value::String(value.to_string())));
+        match section {
+            Some(section) => {
+                document.insert(section.to_string(), Value::Object(properties.collect()));
+            }
+            None => document.extend(properties),
+        }
+    }
+    Value::Object(document)
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    /// `key`, `["key"]` or `['key']`
+    Key(&'a str),
+    /// `[0]`
+    Index(usize),
+    /// `[key=value]`, the first element of an array with `key` set to `value`.
+    /// A `value` starting with `$` is a path from the root of the document.
+    Filter(&'a str, &'a str),
+}
+
+/// Parses a path like `contexts[name=$current-context].context.namespace`
+fn parse_path(path: &str) -> Option<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        if let Some(bracket) = rest.strip_prefix('[') {
+            let quote = bracket.chars().next().filter(|c| *c == '"' || *c == '\'');
+            let (segment, after) = match quote {
+                Some(quote) => {
+                    let (key, after) = bracket[1..].split_once(quote)?;
+                    (Segment::Key(key), after.strip_prefix(']')?)
+                }
+                None => {
+                    let (inner, after) = bracket.split_once(']')?;
+                    let segment = if let Ok(index) = inner.parse() {
+                        Segment::Index(index)
+                    } else if let Some((key, value)) = inner.split_once('=') {
+                        let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+                        Segment::Filter(key.trim(), value)
+                    } else {
+                        Segment::Key(inner)
+                    };
+                    (segment, after)
+                }
+            };
+            segments.push(segment);
+            rest = after;
+        } else {
+            let key_start = if segments.is_empty() {
+                rest
+            } else {
+                rest.strip_prefix('.')?
+            };
+            let end = key_start.find(['.', '[']).unwrap_or(key_start.len());
+            if end == 0 {
+                return None;
+            }
+            segments.push(Segment::Key(&key_start[..end]));
+            rest = &key_start[end..];
+        }
+    }
+
+    Some(segments)
+}
+
+fn lookup<'v>(root: &'v Value, value: &'v Value, segments: &[Segment]) -> Option<&'v Value> {
+    segments
+        .iter()
+        .try_fold(value, |value, segment| match segment {
+            Segment::Key(key) => value.get(key),
+            Segment::Index(index) => value.get(index),
+            Segment::Filter(key, expected) => {
+                let expected = match expected.strip_prefix('$') {
+                    Some(path) => scalar_to_string(lookup(root, root, &parse_path(path)?)?)?,
+                    None => (*expected).to_string(),
+                };
+                value.as_array()?.iter().find(|item| {
+                    item.get(key)
+                        .and_then(scalar_to_string)
+                        .is_some_and(|value| value == expected)
+                })
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use nu_ansi_term::Color;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("contexts[name=$current-context].context.namespace"),
+            Some(vec![
+                Segment::Key("contexts"),
+                Segment::Filter("name", "$current-context"),
+                Segment::Key("context"),
+                Segment::Key("namespace"),
+            ])
+        );
+        assert_eq!(
+            parse_path("[0]['dotted.key'].a"),
+            Some(vec![
+                Segment::Index(0),
+                Segment::Key("dotted.key"),
+                Segment::Key("a"),
+            ])
+        );
+        assert_eq!(parse_path(""), Some(vec![]));
+        assert_eq!(parse_path("a..b"), None);
+        assert_eq!(parse_path("a[0"), None);
+        assert_eq!(parse_path("a[0]b"), None);
+    }
+
+    #[test]
+    fn yaml_from_env_var_with_filter() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let kubeconfig = dir.path().join("kubeconfig");
+        fs::write(
+            &kubeconfig,
+            "current-context: prod\ncontexts:\n  - name: dev\n    context:\n      namespace: sandbox\n  - name: prod\n    context:\n      namespace: shop\n",
+        )?;
+
+        let actual = ModuleRenderer::new("config_value.namespace")
+            .env("KUBECONFIG", kubeconfig.to_string_lossy())
+            .config(toml::toml! {
+                [config_value.namespace]
+                file = "~/.kube/config"
+                file_env = "KUBECONFIG"
+                path = "contexts[name=$current-context].context.namespace"
+            })
+            .collect();
+        let expected = Some(format!(
+            "with {} ",
+            Color::Black.bold().dimmed().paint("shop")
+        ));
+
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn json_by_absolute_path_with_alias() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("deploy.json");
+        fs::write(
+            &file,
+            r#"{"targets": [{"name": "eu-prod-1", "region": "eu"}], "replicas": 3}"#,
+        )?;
+
+        let config = format!(
+            "[config_value.target]
+file = '{}'
+path = 'targets[0].name'
+format = '[$symbol$value]($style)'
+symbol = '🚀 '
+style = 'red'
+[config_value.target.aliases]
+'eu-prod-1' = 'prod'",
+            file.display()
+        );
+        let actual = ModuleRenderer::new("config_value.target")
+            .config(toml::from_str(&config).unwrap())
+            .collect();
+        let expected = Some(Color::Red.paint("🚀 prod").to_string());
+
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn toml_from_ancestor_directory() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let child = dir.path().join("services").join("api");
+        fs::create_dir_all(&child)?;
+        fs::write(
+            dir.path().join("deploy.toml"),
+            "[target]\nname = \"staging\"\nreplicas = 2\n",
+        )?;
+
+        let renderer = |path: &str| {
+            let config = format!(
+                "[config_value.target]
+file = 'deploy.toml'
+path = '{path}'
+format = '$value'"
+            );
+            ModuleRenderer::new("config_value.target")
+                .path(&child)
+                .config(toml::from_str(&config).unwrap())
+                .collect()
+        };
+
+        assert_eq!(renderer("target.name"), Some("staging".to_string()));
+        assert_eq!(renderer("target.replicas"), Some("2".to_string()));
+        // Tables are not shown
+        assert_eq!(renderer("target"), None);
+        dir.close()
+    }
+
+    #[test]
+    fn ini_with_file_format() -> io::Result<()> {
+        let (renderer, home) = ModuleRenderer::new_with_home("config_value.account")?;
+        let config_dir = home.path().join(".config").join("tool");
+        fs::create_dir_all(&config_dir)?;
+        fs::write(
+            config_dir.join("config_default"),
+            "debug = true\n[core]\naccount = alice@example.com\n",
+        )?;
+
+        let actual = renderer
+            .config(toml::toml! {
+                [config_value.account]
+                file = "~/.config/tool/config_default"
+                file_format = "ini"
+                path = "core.account"
+                format = "$value"
+            })
+            .collect();
+
+        assert_eq!(actual, Some("alice@example.com".to_string()));
+        home.close()
+    }
+
+    #[test]
+    fn default_when_missing() {
+        let renderer = |default: Option<&str>| {
+            let mut config = toml::toml! {
+                file = "/does/not/exist.yaml"
+                path = "target"
+                format = "$value"
+            };
+            if let Some(default) = default {
+                config.insert("default".to_string(), default.into());
+            }
+            ModuleRenderer::new("config_value.target")
+                .config(toml::toml! {
+                    [config_value]
+                    target = config
+                })
+                .collect()
+        };
+
+        assert_eq!(renderer(None), None);
+        assert_eq!(renderer(Some("local")), Some("local".to_string()));
+    }
+}
@@ -0,0 +1,109 @@
+use super::utils::hashicorp::{alias, display_address};
+use super::utils::tier::{Tier, TierOverride};
+use super::{Context, Module, ModuleConfig};
+
+use crate::configs::consul::ConsulConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current Consul address and namespace
+///
+/// Will display the Consul address if `CONSUL_HTTP_ADDR` is set, and the namespace from
+/// `CONSUL_NAMESPACE`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("consul");
+    let config: ConsulConfig = ConsulConfig::try_load(module.config);
+
+    if !(context.detect_env_vars(&config.detect_env_vars)) {
+        return None;
+    }
+
+    let address = context.get_env("CONSUL_HTTP_ADDR")?;
+    let namespace = context.get_env("CONSUL_NAMESPACE");
+
+    let tier = Tier::classify(context, "consul", &[namespace.as_deref(), Some(&address)]);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(tier.symbol_or(config.symbol)),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(tier.style_or(config.style))),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "address" => Some(Ok(alias(
+                    &config.address_aliases,
+                    display_address(&address),
+                ))),
+                "namespace" => namespace
+                    .as_deref()
+                    .map(|namespace| Ok(alias(&config.namespace_aliases, namespace))),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name)),
+                _ => None,
+            })
+            .parse(None, Some(context))
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `consul`:\n{error}");
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use nu_ansi_term::Color;
+
+    #[test]
+    fn not_set() {
+        let actual = ModuleRenderer::new("consul")
+            .env("CONSUL_NAMESPACE", "team-a")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn address_and_namespace() {
+        let actual = ModuleRenderer::new("consul")
+            .env("CONSUL_HTTP_ADDR", "127.0.0.1:8500")
+            .env("CONSUL_NAMESPACE", "team-a")
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("🔗 127.0.0.1:8500/team-a")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn address_alias_and_tier() {
+        let actual = ModuleRenderer::new("consul")
+            .env("CONSUL_HTTP_ADDR", "https://consul.prod.example.com")
+            .config(toml::toml! {
+                [environment_tiers.prod]
+                patterns = [".*\\.prod\\..*"]
+                style = "bold red"
+                [consul]
+                format = "via [$symbol$address \\($tier\\)]($style) "
+                [consul.address_aliases]
+                "consul.prod.example.com" = "prod"
+            })
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Red.bold().paint("🔗 prod (prod)")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+}
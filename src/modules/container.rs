@@ -10,67 +10,149 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     use super::ModuleConfig;
     use crate::configs::container::ContainerConfig;
     use crate::formatter::StringFormatter;
-    use crate::utils::{self, read_file};
+    use crate::utils::{context_path, read_file};
 
-    pub fn container_name(context: &Context) -> Option<String> {
-        use crate::utils::context_path;
+    struct Container {
+        runtime: String,
+        name: String,
+    }
+
+    impl Container {
+        fn new(runtime: &str, name: impl Into<String>) -> Self {
+            Self {
+                runtime: runtime.into(),
+                name: name.into(),
+            }
+        }
+    }
+
+    fn hostname(context: &Context) -> Option<String> {
+        context
+            .get_env("HOSTNAME")
+            .or_else(|| read_file(context_path(context, "/etc/hostname")).ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
 
+    // The name of the container as recorded by podman in `/run/.containerenv`
+    fn containerenv_name(context: &Context) -> Option<String> {
+        let contents = read_file(context_path(context, "/run/.containerenv")).ok()?;
+        contents.lines().find_map(|l| {
+            if let Some(name_val) = l.strip_prefix("name=\"") {
+                return name_val.strip_suffix('"').map(|n| n.to_string());
+            }
+
+            l.starts_with("image=\"").then(|| {
+                let r = l.split_at(7).1;
+                let name = r.rfind('/').map(|n| r.split_at(n + 1).1);
+                String::from(name.unwrap_or(r).trim_end_matches('"'))
+            })
+        })
+    }
+
+    // The `container` variable set by the container manager for PID 1, see
+    // https://systemd.io/CONTAINER_INTERFACE/
+    fn container_var(context: &Context) -> Option<String> {
+        if let Ok(s) = read_file(context_path(context, "/run/systemd/container")) {
+            return Some(s.trim().to_string());
+        }
+
+        // Without systemd in the container, fall back to the environment of PID 1,
+        // which is usually only readable by root.
+        let environ = std::fs::read(context_path(context, "/proc/1/environ")).ok()?;
+        environ
+            .split(|b| *b == 0)
+            .find_map(|var| var.strip_prefix(b"container="))
+            .map(|value| String::from_utf8_lossy(value).trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn wsl_distribution(context: &Context) -> Option<String> {
+        if let Some(distro) = context.get_env("WSL_DISTRO_NAME") {
+            return Some(distro);
+        }
+
+        context_path(context, "/proc/sys/fs/binfmt_misc/WSLInterop")
+            .exists()
+            .then(|| "WSL".into())
+    }
+
+    fn detect_container(context: &Context, config: &ContainerConfig) -> Option<Container> {
         if context_path(context, "/proc/vz").exists() && !context_path(context, "/proc/bc").exists()
         {
             // OpenVZ
-            return Some("OpenVZ".into());
+            return Some(Container::new("OpenVZ", "OpenVZ"));
+        }
+
+        if context_path(context, "/run/.toolboxenv").exists() {
+            // Toolbox
+            let name = containerenv_name(context).unwrap_or_else(|| "toolbox".into());
+            return Some(Container::new("Toolbox", name));
+        }
+
+        if context.get_env("DISTROBOX_ENTER_PATH").is_some() {
+            // Distrobox
+            let name = context
+                .get_env("CONTAINER_ID")
+                .or_else(|| containerenv_name(context))
+                .unwrap_or_else(|| "distrobox".into());
+            return Some(Container::new("Distrobox", name));
         }
 
         if context_path(context, "/run/host/container-manager").exists() {
             // OCI
-            return Some("OCI".into());
+            return Some(Container::new("OCI", "OCI"));
         }
 
         if context_path(context, "/dev/incus/sock").exists() {
             // Incus
-            return Some("Incus".into());
+            return Some(Container::new("Incus", "Incus"));
         }
 
-        let container_env_path = context_path(context, "/run/.containerenv");
-        if container_env_path.exists() {
-            // podman and others
+        if context.get_env("KUBERNETES_SERVICE_HOST").is_some()
+            || context_path(context, "/var/run/secrets/kubernetes.io/serviceaccount").exists()
+        {
+            // Kubernetes pods are named after their hostname
+            let name = hostname(context).unwrap_or_else(|| "Kubernetes".into());
+            return Some(Container::new("Kubernetes", name));
+        }
 
-            let image_res = read_file(container_env_path)
-                .map(|s| {
-                    s.lines()
-                        .find_map(|l| {
-                            if let Some(name_val) = l.strip_prefix("name=\"") {
-                                return name_val.strip_suffix('"').map(|n| n.to_string());
-                            }
-
-                            l.starts_with("image=\"").then(|| {
-                                let r = l.split_at(7).1;
-                                let name = r.rfind('/').map(|n| r.split_at(n + 1).1);
-                                String::from(name.unwrap_or(r).trim_end_matches('"'))
-                            })
-                        })
-                        .unwrap_or_else(|| "podman".into())
-                })
-                .unwrap_or_else(|_| "podman".into());
-
-            return Some(image_res);
+        if context_path(context, "/run/.containerenv").exists() {
+            // podman and others
+            let name = containerenv_name(context).unwrap_or_else(|| "podman".into());
+            return Some(Container::new("Podman", name));
         }
 
         // WSL with systemd will set the contents of this file to "wsl"
-        // Avoid showing the container module in that case
+        // Only show WSL distributions when asked to
         // Honor the contents of this file if "docker" and not running in podman or wsl
-        let systemd_path = context_path(context, "/run/systemd/container");
-        if let Ok(s) = utils::read_file(systemd_path) {
-            match s.trim() {
-                "docker" => return Some("Docker".into()),
-                "wsl" => (),
-                _ => return Some("Systemd".into()),
+        match container_var(context).as_deref() {
+            Some("docker") => return Some(Container::new("Docker", "Docker")),
+            Some("wsl") => {
+                return config.show_wsl.then(|| {
+                    let name = wsl_distribution(context).unwrap_or_else(|| "WSL".into());
+                    Container::new("WSL", name)
+                });
             }
+            Some("systemd-nspawn") => {
+                let name = hostname(context).unwrap_or_else(|| "Systemd".into());
+                return Some(Container::new("systemd-nspawn", name));
+            }
+            Some(runtime @ ("lxc" | "lxc-libvirt")) => {
+                let name = hostname(context).unwrap_or_else(|| runtime.into());
+                return Some(Container::new("LXC", name));
+            }
+            Some(runtime) => return Some(Container::new(runtime, "Systemd")),
+            None => (),
         }
 
         if context_path(context, "/.dockerenv").exists() {
             // docker
-            return Some("Docker".into());
+            return Some(Container::new("Docker", "Docker"));
+        }
+
+        if config.show_wsl {
+            return wsl_distribution(context).map(|name| Container::new("WSL", name));
         }
 
         None
@@ -83,7 +165,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let container_name = container_name(context)?;
+    let container = detect_container(context, &config)?;
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -96,7 +178,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "name" => Some(Ok(&container_name)),
+                "name" => Some(Ok(&container.name)),
+                "runtime" => Some(Ok(&container.runtime)),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -328,4 +411,198 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[cfg(target_os = "linux")]
+    fn render_with_files(
+        env: &[(&str, &str)],
+        files: &[(&str, &[u8])],
+        config: toml::Table,
+    ) -> std::io::Result<Option<String>> {
+        let mut renderer = ModuleRenderer::new("container").config(config);
+        for (key, value) in env {
+            renderer = renderer.env(key, *value);
+        }
+
+        for (file, contents) in files {
+            let path = renderer.root_path().join(file);
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, contents)?;
+        }
+
+        Ok(renderer.collect())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_kubernetes_pod() -> std::io::Result<()> {
+        let actual = render_with_files(
+            &[
+                ("KUBERNETES_SERVICE_HOST", "10.96.0.1"),
+                ("HOSTNAME", "api-7d9c5b6f4-x2x8k"),
+            ],
+            &[],
+            toml::toml! {
+                [container]
+                format = "[$runtime:$name]($style)"
+            },
+        )?;
+
+        let expected = Some(format!(
+            "{}",
+            Color::Red
+                .bold()
+                .dimmed()
+                .paint("Kubernetes:api-7d9c5b6f4-x2x8k")
+        ));
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_kubernetes_service_account() -> std::io::Result<()> {
+        let actual = render_with_files(
+            &[],
+            &[
+                (
+                    "var/run/secrets/kubernetes.io/serviceaccount/namespace",
+                    b"default",
+                ),
+                ("etc/hostname", b"worker-0\n"),
+            ],
+            toml::toml! {
+                [container]
+                format = "[$runtime:$name]($style)"
+            },
+        )?;
+
+        let expected = Some(format!(
+            "{}",
+            Color::Red.bold().dimmed().paint("Kubernetes:worker-0")
+        ));
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_systemd_nspawn_machine_name() -> std::io::Result<()> {
+        let actual = render_with_files(
+            &[],
+            &[
+                ("run/systemd/container", b"systemd-nspawn\n"),
+                ("etc/hostname", b"build-machine\n"),
+            ],
+            toml::toml! {
+                [container]
+                format = "[$runtime:$name]($style)"
+            },
+        )?;
+
+        let expected = Some(format!(
+            "{}",
+            Color::Red
+                .bold()
+                .dimmed()
+                .paint("systemd-nspawn:build-machine")
+        ));
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_lxc_from_pid1_environ() -> std::io::Result<()> {
+        let actual = render_with_files(
+            &[],
+            &[
+                (
+                    "proc/1/environ",
+                    b"PATH=/usr/bin\0container=lxc\0TERM=linux\0",
+                ),
+                ("etc/hostname", b"web01\n"),
+            ],
+            toml::toml! {
+                [container]
+                format = "[$runtime:$name]($style)"
+            },
+        )?;
+
+        let expected = Some(format!("{}", Color::Red.bold().dimmed().paint("LXC:web01")));
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_toolbox_and_distrobox() -> std::io::Result<()> {
+        let config = toml::toml! {
+            [container]
+            format = "[$runtime:$name]($style)"
+        };
+
+        let toolbox = render_with_files(
+            &[],
+            &[
+                ("run/.toolboxenv", b""),
+                ("run/.containerenv", b"name=\"fedora-toolbox-40\"\n"),
+            ],
+            config.clone(),
+        )?;
+        let expected = Some(format!(
+            "{}",
+            Color::Red
+                .bold()
+                .dimmed()
+                .paint("Toolbox:fedora-toolbox-40")
+        ));
+        assert_eq!(toolbox, expected);
+
+        let distrobox = render_with_files(
+            &[
+                ("DISTROBOX_ENTER_PATH", "/usr/bin/distrobox-enter"),
+                ("CONTAINER_ID", "arch"),
+            ],
+            &[("run/.containerenv", b"name=\"arch\"\n")],
+            config,
+        )?;
+        let expected = Some(format!(
+            "{}",
+            Color::Red.bold().dimmed().paint("Distrobox:arch")
+        ));
+        assert_eq!(distrobox, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_wsl_distribution() -> std::io::Result<()> {
+        let env = [("WSL_DISTRO_NAME", "Ubuntu-24.04")];
+        let files: [(&str, &[u8]); 1] = [("run/systemd/container", b"wsl\n")];
+
+        let hidden = render_with_files(&env, &files, toml::Table::new())?;
+        assert_eq!(hidden, None);
+
+        let actual = render_with_files(
+            &env,
+            &files,
+            toml::toml! {
+                [container]
+                show_wsl = true
+                format = "[$runtime:$name]($style)"
+            },
+        )?;
+        let expected = Some(format!(
+            "{}",
+            Color::Red.bold().dimmed().paint("WSL:Ubuntu-24.04")
+        ));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -3,7 +3,6 @@ use std::fmt::{self, Debug};
 use std::io::Write;
 use std::path::Path;
 use std::process::{Command, Stdio};
-use std::time::Duration;
 
 use process_control::{ChildExt, Control, Output};
 
@@ -207,7 +206,7 @@ fn shell_command(cmd: &str, config: &CustomConfig, context: &Context) -> Option<
 
     if !config.ignore_timeout {
         output = output
-            .time_limit(Duration::from_millis(context.root_config.command_timeout))
+            .time_limit(context.command_timeout())
             .terminate_for_timeout()
     }
 
@@ -215,7 +214,7 @@ fn shell_command(cmd: &str, config: &CustomConfig, context: &Context) -> Option<
         None => {
             log::warn!("Executing custom command {cmd:?} timed out.");
             log::warn!(
-                "You can set command_timeout in your config to a higher value or set ignore_timeout to true for this module to allow longer-running commands to keep executing."
+                "You can set command_timeout or the module's timeout in your config to a higher value or set ignore_timeout to true for this module to allow longer-running commands to keep executing."
             );
             None
         }
@@ -418,6 +417,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn disabled_on_slow_fs() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let shell = SHELL
+            .iter()
+            .map(std::borrow::ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        let render = |fs_type: &str| {
+            let shell = shell.clone();
+            ModuleRenderer::new("custom.foo")
+                .path(dir.path())
+                .fs_type(fs_type)
+                .config(toml::toml! {
+                    slow_fs_disabled_modules = ["custom.foo"]
+                    [custom.foo]
+                    format = "$output"
+                    command = "echo foo"
+                    shell = shell
+                    when = true
+                })
+                .collect()
+        };
+
+        assert_eq!(render("ext4"), Some("foo".into()));
+        assert_eq!(render("nfs4"), None);
+        dir.close()
+    }
+
     #[test]
     fn command_can_fail() -> io::Result<()> {
         assert_eq!(render_cmd(FAILING_COMMAND)?, None);
@@ -683,6 +710,34 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn module_timeout_cmd() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let shell = if cfg!(windows) {
+            "powershell".to_owned()
+        } else {
+            "sh".to_owned()
+        };
+
+        // The module's timeout takes precedence over the long global timeout
+        let actual = ModuleRenderer::new("custom.test")
+            .path(dir.path())
+            .config(toml::toml! {
+                command_timeout = 100_000
+                [custom.test]
+                format = "test"
+                when = "sleep 3"
+                shell = shell
+                timeout = 100
+            })
+            .collect();
+        let expected = None;
+        assert_eq!(expected, actual);
+
+        dir.close()
+    }
+
     #[test]
     fn config_aliases_work() -> std::io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -1,9 +1,16 @@
 use super::{Context, Module, ModuleConfig};
 
+use super::utils::cache::CacheInputs;
 use crate::configs::deno::DenoConfig;
 use crate::formatter::StringFormatter;
 use crate::formatter::VersionFormatter;
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[],
+    env_vars: &[],
+};
+
 /// Creates a module with the current Deno version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("deno");
@@ -5,17 +5,26 @@ use super::utils::directory_win as directory_utils;
 use super::utils::path::PathExt as SPathExt;
 use indexmap::IndexMap;
 use path_slash::{PathBufExt, PathExt};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::fs;
 use std::iter::FromIterator;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::{Context, Module};
 
-use super::utils::directory::truncate;
+use super::utils::directory::{truncate, truncate_to_width};
 use crate::config::ModuleConfig;
-use crate::configs::directory::DirectoryConfig;
+use crate::configs::directory::{DirectoryConfig, DirectoryLocationConfig};
 use crate::formatter::StringFormatter;
+use crate::print::UnicodeWidthGraphemes;
+use crate::segment::Segment;
+
+/// Symbol replacing the components dropped by width-aware truncation
+const WIDTH_TRUNCATION_ELLIPSIS: &str = "…";
 
 /// Creates a module with the current logical or physical directory
 ///
@@ -26,11 +35,21 @@ use crate::formatter::StringFormatter;
 ///   the home directory will be contracted to `~`, or the set `HOME_SYMBOL`
 /// - Paths containing a git repo will contract to begin at the repo root
 ///
+/// **Locations**
+/// Paths inside a user-provided location are displayed relative to its symbol.
+/// Locations are either path prefixes or the names of repo, VCS and project roots
+///
 /// **Substitution**
-/// Paths will undergo user-provided substitutions of substrings
+/// Paths will undergo user-provided substitutions of substrings and regex patterns
+///
+/// **Abbreviation**
+/// With `use_unique_prefix`, parent directories are shortened to the shortest prefix
+/// that is unique among their siblings
 ///
 /// **Truncation**
 /// Paths will be limited in length to `3` path components by default.
+/// With `truncate_to_width`, paths are instead shortened only as far as needed
+/// to fit into the columns left by the other modules on the prompt line.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("directory");
     let config: DirectoryConfig = DirectoryConfig::try_load(module.config);
@@ -56,41 +75,89 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     } else {
         None
     };
-    let dir_string = if config.truncate_to_repo {
+    let contracted_repo_root = if config.truncate_to_repo {
         repo.and_then(|r| r.workdir.as_ref())
             .filter(|&root| root != &home_dir)
-            .and_then(|root| contract_repo_path(display_dir, root))
     } else {
         None
     };
 
+    // Locations given by name only need the roots to be looked up when they are configured
+    let roots = if config.locations.keys().any(|key| is_root_name(key)) {
+        find_roots(context, &config.root_markers)
+    } else {
+        Vec::new()
+    };
+
+    // Named locations replace their path, unless the repo root is nested inside them
+    let location = find_location(
+        &config.locations,
+        &[display_dir, physical_dir],
+        &roots,
+        &home_dir,
+    )
+    .filter(|location| {
+        contracted_repo_root.is_none_or(|repo_root| {
+            let repo_root = real_path(repo_root);
+            let location_root = real_path(&location.root);
+            repo_root == location_root || !repo_root.starts_with(location_root)
+        })
+    });
+
+    let dir_string = if location.is_some() {
+        None
+    } else {
+        contracted_repo_root.and_then(|root| contract_repo_path(display_dir, root))
+    };
+
     let mut is_truncated = dir_string.is_some();
 
     // the home directory if required.
-    let dir_string = dir_string
-        .unwrap_or_else(|| contract_path(display_dir, &home_dir, config.home_symbol).to_string());
+    let dir_string = match &location {
+        Some(location) => location.sub_path.clone(),
+        None => dir_string.unwrap_or_else(|| {
+            contract_path(display_dir, &home_dir, config.home_symbol).to_string()
+        }),
+    };
 
     #[cfg(windows)]
     let dir_string = remove_extended_path_prefix(dir_string);
 
     // Apply path substitutions
     let dir_string = substitute_path(dir_string, &config.substitutions);
+    let dir_string = substitute_path_regex(dir_string, &config.regex_substitutions);
+    let has_substitutions =
+        !config.substitutions.is_empty() || !config.regex_substitutions.is_empty();
+
+    // Width-aware truncation replaces the fixed number of path components
+    let width_budget = if config.truncate_to_width {
+        context.directory_width()
+    } else {
+        None
+    };
+    let truncation_length = if width_budget.is_some() {
+        0
+    } else {
+        config.truncation_length
+    };
 
     // Truncate the dir string to the maximum number of path components
-    let dir_string =
-        if let Some(truncated) = truncate(&dir_string, config.truncation_length as usize) {
-            is_truncated = true;
-            truncated
-        } else {
-            dir_string
-        };
+    let dir_string = if let Some(truncated) = truncate(&dir_string, truncation_length as usize) {
+        is_truncated = true;
+        truncated
+    } else {
+        dir_string
+    };
 
     let prefix = if is_truncated {
         // Substitutions could have changed the prefix, so don't allow them and
         // fish-style path contraction together
-        if config.fish_style_pwd_dir_length > 0 && config.substitutions.is_empty() {
+        if config.fish_style_pwd_dir_length > 0 && !has_substitutions {
             // If user is using fish style path, we need to add the segment first
-            let contracted_home_dir = contract_path(display_dir, &home_dir, config.home_symbol);
+            let contracted_home_dir = match &location {
+                Some(location) => Cow::from(location.sub_path.as_str()),
+                None => contract_path(display_dir, &home_dir, config.home_symbol),
+            };
             to_fish_style(
                 config.fish_style_pwd_dir_length as usize,
                 &contracted_home_dir,
@@ -103,15 +170,42 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         String::new()
     };
 
+    // Shorten parent directories to their unique prefixes. Like fish-style paths,
+    // this is incompatible with substitutions.
+    let mut sibling_cache = (config.use_unique_prefix && !has_substitutions)
+        .then(|| SiblingCache::load(context.get_cache_dir().join(SIBLING_CACHE_FILE)));
+    let repo_root_index = repo
+        .and_then(|r| r.workdir.as_ref())
+        .and_then(|root| contract_repo_path(display_dir, root))
+        .map(|contracted_path| contracted_path.split('/').count() - 1);
+    let dir_string = match sibling_cache.as_mut() {
+        Some(cache) => to_unique_prefix_style(&dir_string, display_dir, repo_root_index, cache),
+        None => dir_string,
+    };
+
     let path_vec = match &repo.and_then(|r| r.workdir.as_ref()) {
+        // The path continues after the location symbol
+        _ if location.is_some() => {
+            let path = prefix + dir_string.as_str();
+            if path.is_empty() {
+                [String::new(), String::new(), path]
+            } else {
+                [String::new(), String::new(), format!("/{path}")]
+            }
+        }
         Some(repo_root) if config.repo_root_style.is_some() => {
             let contracted_path = contract_repo_path(display_dir, repo_root)?;
+            let contracted_path = match sibling_cache.as_mut() {
+                Some(cache) => {
+                    to_unique_prefix_style(&contracted_path, display_dir, repo_root_index, cache)
+                }
+                None => contracted_path,
+            };
             let repo_path_vec: Vec<&str> = contracted_path.split('/').collect();
             let after_repo_root = contracted_path.replacen(repo_path_vec[0], "", 1);
             let num_segments_after_root = after_repo_root.split('/').count();
 
-            if config.truncation_length == 0
-                || ((num_segments_after_root - 1) as i64) < config.truncation_length
+            if truncation_length == 0 || ((num_segments_after_root - 1) as i64) < truncation_length
             {
                 let root = repo_path_vec[0];
                 let before = before_root_dir(&dir_string, &contracted_path);
@@ -123,43 +217,78 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         _ => [String::new(), String::new(), prefix + dir_string.as_str()],
     };
 
-    let path_vec = if config.use_os_path_sep {
-        path_vec.map(|i| convert_path_sep(&i))
-    } else {
-        path_vec
-    };
+    if let Some(cache) = sibling_cache {
+        cache.save();
+    }
 
-    let display_format = if path_vec[0].is_empty() && path_vec[1].is_empty() {
-        config.format
-    } else {
-        config.repo_root_format
-    };
     let repo_root_style = config.repo_root_style.unwrap_or(config.style);
     let before_repo_root_style = config.before_repo_root_style.unwrap_or(config.style);
+    let location_style = location
+        .as_ref()
+        .and_then(|location| location.config.style)
+        .unwrap_or(config.style);
+
+    let render = |path_vec: &[String; 3]| {
+        let path_vec = if config.use_os_path_sep {
+            path_vec.clone().map(|i| convert_path_sep(&i))
+        } else {
+            path_vec.clone()
+        };
 
-    let parsed = StringFormatter::new(display_format).and_then(|formatter| {
-        formatter
-            .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
-                "read_only_style" => Some(Ok(config.read_only_style)),
-                "repo_root_style" => Some(Ok(repo_root_style)),
-                "before_repo_root_style" => Some(Ok(before_repo_root_style)),
-                _ => None,
-            })
-            .map(|variable| match variable {
-                "path" => Some(Ok(path_vec[2].as_str())),
-                "before_root_path" => Some(Ok(path_vec[0].as_str())),
-                "repo_root" => Some(Ok(path_vec[1].as_str())),
-                "read_only" => {
-                    if is_readonly_dir(physical_dir) {
-                        Some(Ok(config.read_only))
-                    } else {
-                        None
+        let display_format = if path_vec[0].is_empty() && path_vec[1].is_empty() {
+            config.format
+        } else {
+            config.repo_root_format
+        };
+
+        StringFormatter::new(display_format).and_then(|formatter| {
+            formatter
+                .map_style(|variable| match variable {
+                    "style" => Some(Ok(config.style)),
+                    "read_only_style" => Some(Ok(config.read_only_style)),
+                    "repo_root_style" => Some(Ok(repo_root_style)),
+                    "before_repo_root_style" => Some(Ok(before_repo_root_style)),
+                    "location_style" => Some(Ok(location_style)),
+                    _ => None,
+                })
+                .map(|variable| match variable {
+                    "location" => location.as_ref().map(|location| Ok(location.config.symbol)),
+                    "fs_type" => context.fs_type().map(Ok),
+                    "path" => Some(Ok(path_vec[2].as_str())),
+                    "before_root_path" => Some(Ok(path_vec[0].as_str())),
+                    "repo_root" => Some(Ok(path_vec[1].as_str())),
+                    "read_only" => {
+                        if is_readonly_dir(physical_dir) {
+                            Some(Ok(config.read_only))
+                        } else {
+                            None
+                        }
                     }
-                }
-                _ => None,
-            })
-            .parse(None, Some(context))
+                    _ => None,
+                })
+                .parse(None, Some(context))
+        })
+    };
+
+    let parsed = render(&path_vec).and_then(|segments| {
+        let Some(max_width) = width_budget else {
+            return Ok(segments);
+        };
+        let width = segments.iter().map(Segment::width_graphemes).sum::<usize>();
+        if width <= max_width {
+            return Ok(segments);
+        }
+
+        // Only the path variables can shrink, the rest of the format is fixed
+        let path_width = path_vec.iter().map(|p| p.width_graphemes()).sum::<usize>();
+        let fixed_width = width.saturating_sub(path_width);
+        let fish_length = config.fish_style_pwd_dir_length.max(1) as usize;
+        render(&fit_path_to_width(
+            path_vec,
+            max_width.saturating_sub(fixed_width),
+            fish_length,
+            location.is_some(),
+        ))
     });
 
     module.set_segments(match parsed {
@@ -173,6 +302,45 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// Shorten the `[before_root_path, repo_root, path]` triple to fit into `max_width` columns
+///
+/// The part after the repo root is shortened first, then the part before it.
+/// The repo root itself is never shortened. With `after_location`, the path
+/// continues a location symbol and keeps its leading separator.
+fn fit_path_to_width(
+    path_vec: [String; 3],
+    max_width: usize,
+    fish_length: usize,
+    after_location: bool,
+) -> [String; 3] {
+    let [before, root, path] = path_vec;
+
+    let path_budget = max_width.saturating_sub(before.width_graphemes() + root.width_graphemes());
+    // Keep the separator between the repo root or location and the rest of the path
+    let path = match path.strip_prefix('/') {
+        Some(after_root) if !root.is_empty() || after_location => truncate_to_width(
+            after_root,
+            path_budget.saturating_sub(1),
+            fish_length,
+            WIDTH_TRUNCATION_ELLIPSIS,
+        )
+        .map(|after_root| format!("/{after_root}")),
+        _ => truncate_to_width(&path, path_budget, fish_length, WIDTH_TRUNCATION_ELLIPSIS),
+    }
+    .unwrap_or(path);
+
+    let before_budget = max_width.saturating_sub(root.width_graphemes() + path.width_graphemes());
+    let before = truncate_to_width(
+        &before,
+        before_budget,
+        fish_length,
+        WIDTH_TRUNCATION_ELLIPSIS,
+    )
+    .unwrap_or(before);
+
+    [before, root, path]
+}
+
 #[cfg(windows)]
 fn remove_extended_path_prefix(path: String) -> String {
     fn try_trim_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
@@ -299,6 +467,100 @@ fn substitute_path(dir_string: String, substitutions: &IndexMap<String, &str>) -
     substituted_dir
 }
 
+/// Perform a list of regex substitutions on the path
+///
+/// Given a list of (pattern, replacement) pairs, this will replace all matches
+/// of each pattern, in order. Replacements may refer to capture groups with `$1` or `${name}`.
+/// Invalid patterns are skipped.
+fn substitute_path_regex(dir_string: String, substitutions: &IndexMap<String, &str>) -> String {
+    let mut substituted_dir = dir_string;
+    for (pattern, replacement) in substitutions {
+        match Regex::new(pattern) {
+            Ok(re) => {
+                substituted_dir = re.replace_all(&substituted_dir, *replacement).into_owned();
+            }
+            Err(e) => {
+                log::warn!("Invalid regex substitution {pattern:?} in module `directory`:\n{e}");
+            }
+        }
+    }
+    substituted_dir
+}
+
+/// A configured location containing the current directory
+struct Location<'a, 'b> {
+    config: &'b DirectoryLocationConfig<'a>,
+    /// The directory the location refers to
+    root: PathBuf,
+    /// The path of the current directory relative to `root`
+    sub_path: String,
+}
+
+/// Find the deepest configured location containing one of `dirs`
+///
+/// Locations are path prefixes, which may start with `~` for the home directory,
+/// or the directory name of one of the `roots` containing the current directory.
+fn find_location<'a, 'b>(
+    locations: &'b IndexMap<String, DirectoryLocationConfig<'a>>,
+    dirs: &[&Path],
+    roots: &[PathBuf],
+    home_dir: &Path,
+) -> Option<Location<'a, 'b>> {
+    locations
+        .iter()
+        .filter_map(|(key, config)| {
+            let root = if is_root_name(key) {
+                roots
+                    .iter()
+                    .filter(|root| root.file_name().is_some_and(|name| name == key.as_str()))
+                    .max_by_key(|root| root.components().count())?
+                    .clone()
+            } else {
+                match key.strip_prefix('~') {
+                    Some(rest) => home_dir.join(rest.trim_start_matches(['/', '\\'])),
+                    None => PathBuf::from(key),
+                }
+            };
+            let dir = dirs.iter().find(|dir| dir.normalised_starts_with(&root))?;
+            let sub_path = dir
+                .without_prefix()
+                .strip_prefix(root.without_prefix())
+                .ok()?
+                .to_slash_lossy()
+                .into_owned();
+            Some(Location {
+                config,
+                root,
+                sub_path,
+            })
+        })
+        .max_by_key(|location| location.root.components().count())
+}
+
+/// Whether a location key names a root directory rather than being a path prefix
+fn is_root_name(key: &str) -> bool {
+    !key.starts_with('~') && !Path::new(key).has_root()
+}
+
+/// The root of the current git repo, and the directories above the current directory
+/// containing one of `markers`, like VCS folders or project files
+fn find_roots(context: &Context, markers: &[&str]) -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = context
+        .current_dir
+        .ancestors()
+        .filter(|dir| markers.iter().any(|marker| dir.join(marker).exists()))
+        .map(Path::to_path_buf)
+        .collect();
+    if let Some(workdir) = context
+        .get_repo()
+        .ok()
+        .and_then(|repo| repo.workdir.clone())
+    {
+        roots.push(workdir);
+    }
+    roots
+}
+
 /// Takes part before contracted path and replaces it with fish style path
 ///
 /// Will take the first letter of each directory before the contracted path and
@@ -334,6 +596,153 @@ fn to_fish_style(pwd_dir_length: usize, dir_string: &str, truncated_dir_string:
         .join("/")
 }
 
+/// Shortens each parent component of the path to its shortest unique prefix
+///
+/// Components are matched from the end against the ancestors of `display_dir`.
+/// The current directory, the component at `keep_index` from the end (the repo root)
+/// and components that don't name their directory (e.g. the home symbol) are left as is.
+///
+/// Absolute Path: `/Users/Bob/services/billing-api/src`
+/// Siblings of `billing-api`: `billing-worker`, `users-api`
+/// With Unique Prefix: `/U/B/s/billing-a/src`
+fn to_unique_prefix_style(
+    dir_string: &str,
+    display_dir: &Path,
+    keep_index: Option<usize>,
+    cache: &mut SiblingCache,
+) -> String {
+    let mut components = dir_string.split('/').map(String::from).collect::<Vec<_>>();
+
+    for (i, (component, dir)) in components
+        .iter_mut()
+        .rev()
+        .zip(display_dir.ancestors())
+        .enumerate()
+        .skip(1)
+    {
+        if Some(i) == keep_index {
+            continue;
+        }
+        let (Some(parent), Some(name)) = (dir.parent(), dir.file_name()) else {
+            continue;
+        };
+        if name.to_str() != Some(component.as_str()) {
+            continue;
+        }
+        if let Some(siblings) = cache.siblings(parent) {
+            *component = unique_prefix(component, siblings);
+        }
+    }
+
+    components.join("/")
+}
+
+/// Returns the shortest prefix of `name` that no other name in `siblings` starts with
+///
+/// If `name` is itself a prefix of a sibling, it is returned unchanged.
+fn unique_prefix(name: &str, siblings: &[String]) -> String {
+    let mut prefix = String::new();
+    for grapheme in name.graphemes(true) {
+        prefix.push_str(grapheme);
+        let ambiguous = siblings
+            .iter()
+            .any(|sibling| sibling != name && sibling.starts_with(&prefix));
+        if !ambiguous {
+            return prefix;
+        }
+    }
+    prefix
+}
+
+/// Name of the file in the cache directory holding the listings of `SiblingCache`
+const SIBLING_CACHE_FILE: &str = "directory_siblings.json";
+
+/// Maximum number of directory listings kept by `SiblingCache`
+const SIBLING_CACHE_SIZE: usize = 256;
+
+/// A cached list of the subdirectories of a directory
+#[derive(Deserialize, Serialize)]
+struct SiblingListing {
+    /// Modification time of the directory when it was listed
+    modified: SystemTime,
+    names: Vec<String>,
+}
+
+/// A cache of subdirectory names persisted between prompts
+///
+/// Listings are invalidated when the modification time of the listed directory changes,
+/// which happens whenever one of its entries is created, removed or renamed.
+struct SiblingCache {
+    path: PathBuf,
+    listings: IndexMap<PathBuf, SiblingListing>,
+    changed: bool,
+}
+
+impl SiblingCache {
+    fn load(path: PathBuf) -> Self {
+        let listings = fs::read(&path)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_slice(&content)
+                    .map_err(|e| log::debug!("Ignoring invalid directory cache {path:?}: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        Self {
+            path,
+            listings,
+            changed: false,
+        }
+    }
+
+    /// Returns the names of the subdirectories of `dir`
+    fn siblings(&mut self, dir: &Path) -> Option<&[String]> {
+        let modified = fs::metadata(dir).and_then(|m| m.modified()).ok()?;
+        let is_fresh = self
+            .listings
+            .get(dir)
+            .is_some_and(|listing| listing.modified == modified);
+
+        if !is_fresh {
+            let names = fs::read_dir(dir)
+                .ok()?
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect();
+            self.listings.shift_remove(dir);
+            self.listings
+                .insert(dir.to_path_buf(), SiblingListing { modified, names });
+            while self.listings.len() > SIBLING_CACHE_SIZE {
+                self.listings.shift_remove_index(0);
+            }
+            self.changed = true;
+        }
+
+        self.listings
+            .get(dir)
+            .map(|listing| listing.names.as_slice())
+    }
+
+    fn save(self) {
+        if !self.changed {
+            return;
+        }
+        let result = self
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| {
+                let content = serde_json::to_vec(&self.listings).map_err(std::io::Error::from)?;
+                fs::write(&self.path, content)
+            });
+        if let Err(e) = result {
+            log::debug!("Unable to write directory cache {:?}: {e}", self.path);
+        }
+    }
+}
+
 /// Convert the path separators in `path` to the OS specific path separators.
 fn convert_path_sep(path: &str) -> String {
     PathBuf::from_slash(path).to_string_lossy().into_owned()
@@ -495,6 +904,97 @@ mod tests {
         assert_eq!(output, "~/s/t/目/a̐/");
     }
 
+    #[test]
+    fn unique_prefix_among_siblings() {
+        let siblings = ["billing-api", "billing-worker", "users-api"].map(String::from);
+        assert_eq!(unique_prefix("billing-api", &siblings), "billing-a");
+        assert_eq!(unique_prefix("users-api", &siblings), "u");
+    }
+
+    #[test]
+    fn unique_prefix_of_prefix_sibling() {
+        let siblings = ["rocket", "rocket-controls"].map(String::from);
+        assert_eq!(unique_prefix("rocket", &siblings), "rocket");
+    }
+
+    #[test]
+    fn unique_prefix_with_unicode() {
+        let siblings = ["目录", "目标"].map(String::from);
+        assert_eq!(unique_prefix("目录", &siblings), "目录");
+        assert_eq!(unique_prefix("a̐éö̲", &["a̐x".to_string()]), "a̐é");
+    }
+
+    #[test]
+    fn unique_prefix_directory() -> io::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let cache_dir = TempDir::new()?;
+        let services = tmp_dir.path().join("services");
+        for sibling in ["billing-worker", "users-api", "billing-api/src"] {
+            fs::create_dir_all(services.join(sibling))?;
+        }
+        fs::write(services.join("billing-api-notes.txt"), "")?;
+        let dir = services.join("billing-api").join("src");
+
+        let actual = ModuleRenderer::new("directory")
+            .config(toml::toml! {
+                [directory]
+                use_unique_prefix = true
+            })
+            .env("STARSHIP_CACHE", cache_dir.path().to_str().unwrap())
+            .path(&dir)
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Cyan
+                .bold()
+                .paint(convert_path_sep("s/billing-a/src"))
+        ));
+
+        assert_eq!(expected, actual);
+        assert!(cache_dir.path().join(SIBLING_CACHE_FILE).exists());
+        tmp_dir.close()?;
+        cache_dir.close()
+    }
+
+    #[test]
+    fn sibling_cache_refreshes_changed_directory() -> io::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let cache_file = tmp_dir.path().join("cache").join(SIBLING_CACHE_FILE);
+        let parent = tmp_dir.path().join("parent");
+        fs::create_dir_all(parent.join("rocket"))?;
+
+        let mut cache = SiblingCache::load(cache_file.clone());
+        assert_eq!(
+            cache.siblings(&parent),
+            Some(["rocket".to_string()].as_slice())
+        );
+        cache.save();
+
+        let mut cache = SiblingCache::load(cache_file.clone());
+        assert!(cache.listings.contains_key(&parent));
+        // Make the listing stale even on file systems with coarse timestamps
+        let modified = fs::metadata(&parent)?.modified()?;
+        cache.listings[&parent].modified = modified - std::time::Duration::from_secs(1);
+        fs::create_dir(parent.join("rover"))?;
+
+        let mut siblings = cache.siblings(&parent).unwrap().to_vec();
+        siblings.sort();
+        assert_eq!(siblings, ["rocket", "rover"]);
+        assert!(cache.changed);
+        tmp_dir.close()
+    }
+
+    #[test]
+    fn fit_path_to_width_keeps_repo_root() {
+        let path_vec = [
+            "~/Dev/Nix/".to_string(),
+            "nixpkgs".to_string(),
+            "/pkgs/development/tools".to_string(),
+        ];
+        let output = fit_path_to_width(path_vec, 22, 1, false);
+        assert_eq!(output, ["~/D/N/", "nixpkgs", "/…/tools"]);
+    }
+
     fn init_repo(path: &Path) -> io::Result<()> {
         create_command("git")?
             .args(["init"])
@@ -650,6 +1150,48 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn regex_substitution_with_capture_groups() {
+        let actual = ModuleRenderer::new("directory")
+            .path("/src/services/billing-api/cmd")
+            .config(toml::toml! {
+                [directory]
+                truncation_length = 0
+                [directory.regex_substitutions]
+                "^/src/services/([^/]+)" = "svc:$1"
+            })
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Cyan
+                .bold()
+                .paint(convert_path_sep("svc:billing-api/cmd"))
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn regex_substitution_after_literal_substitution() {
+        let actual = ModuleRenderer::new("directory")
+            .path("/path/to/sub")
+            .config(toml::toml! {
+                [directory.substitutions]
+                "/path" = "/long/path"
+                [directory.regex_substitutions]
+                "[(]invalid" = "ignored"
+                "(" = "invalid"
+                "long/([a-z]+)" = "${1}s"
+            })
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Cyan.bold().paint(convert_path_sep("/paths/to/sub"))
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn substitution_order() {
         let actual = ModuleRenderer::new("directory")
@@ -1075,6 +1617,209 @@ mod tests {
         tmp_dir.close()
     }
 
+    #[test]
+    fn fs_type_on_slow_fs() {
+        let actual = ModuleRenderer::new("directory")
+            .path("/home/astronaut")
+            .config(toml::toml! {
+                [directory]
+                format = "[$path]($style)( on [$fs_type](red))"
+            })
+            .fs_type("nfs4")
+            .collect();
+        let expected = Some(format!(
+            "{} on {}",
+            Color::Cyan
+                .bold()
+                .paint(convert_path_sep("/home/astronaut")),
+            Color::Red.paint("nfs4")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn fs_type_on_local_fs() {
+        let actual = ModuleRenderer::new("directory")
+            .path("/home/astronaut")
+            .config(toml::toml! {
+                [directory]
+                format = "[$path]($style)( on [$fs_type](red))"
+            })
+            .fs_type("ext4")
+            .collect();
+        let expected = Some(format!(
+            "{} on {}",
+            Color::Cyan
+                .bold()
+                .paint(convert_path_sep("/home/astronaut")),
+            Color::Red.paint("ext4")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn location_in_home() -> io::Result<()> {
+        let (renderer, home) = ModuleRenderer::new_with_home("directory")?;
+        let dir = home.path().join("work/acme/services/billing");
+        fs::create_dir_all(&dir)?;
+
+        let actual = renderer
+            .config(toml::toml! {
+                [directory.locations]
+                "~/work" = { symbol = "W" }
+                "~/work/acme" = { symbol = "A acme", style = "bold purple" }
+            })
+            .path(&dir)
+            .collect();
+        let expected = Some(format!(
+            "{}A acme{} ",
+            Color::Purple.bold().prefix(),
+            Color::Cyan.paint(convert_path_sep("/services/billing"))
+        ));
+
+        assert_eq!(expected, actual);
+        home.close()
+    }
+
+    #[test]
+    fn location_root() -> io::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let dir = tmp_dir.path().join("acme");
+        fs::create_dir_all(&dir)?;
+
+        let config = format!(
+            "[directory.locations]\n'{}' = {{ symbol = 'A' }}",
+            dir.display()
+        );
+        let actual = ModuleRenderer::new("directory")
+            .config(toml::from_str(&config).unwrap())
+            .path(&dir)
+            .collect();
+        let expected = Some(format!("{} ", Color::Cyan.bold().paint("A")));
+
+        assert_eq!(expected, actual);
+        tmp_dir.close()
+    }
+
+    #[test]
+    fn location_at_git_repo_root() -> io::Result<()> {
+        let (renderer, home) = ModuleRenderer::new_with_home("directory")?;
+        let repo_dir = home.path().join("work/acme");
+        let dir = repo_dir.join("src");
+        fs::create_dir_all(&dir)?;
+        init_repo(&repo_dir)?;
+
+        let actual = renderer
+            .config(toml::toml! {
+                [directory.locations]
+                "~/work/acme" = { symbol = "A acme", style = "bold purple" }
+            })
+            .path(&dir)
+            .collect();
+        let expected = Some(format!(
+            "{}A acme{} ",
+            Color::Purple.bold().prefix(),
+            Color::Cyan.paint(convert_path_sep("/src"))
+        ));
+
+        assert_eq!(expected, actual);
+        home.close()
+    }
+
+    #[test]
+    fn git_repo_inside_location() -> io::Result<()> {
+        let (renderer, home) = ModuleRenderer::new_with_home("directory")?;
+        let repo_dir = home.path().join("work/acme");
+        let dir = repo_dir.join("src");
+        fs::create_dir_all(&dir)?;
+        init_repo(&repo_dir)?;
+
+        let actual = renderer
+            .config(toml::toml! {
+                [directory.locations]
+                "~/work" = { symbol = "W" }
+            })
+            .path(&dir)
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Cyan.bold().paint(convert_path_sep("acme/src"))
+        ));
+
+        assert_eq!(expected, actual);
+        home.close()
+    }
+
+    #[test]
+    fn location_named_after_git_repo_root() -> io::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let repo_dir = tmp_dir.path().join("checkouts").join("acme");
+        let dir = repo_dir.join("src");
+        fs::create_dir_all(&dir)?;
+        init_repo(&repo_dir)?;
+
+        let actual = ModuleRenderer::new("directory")
+            .config(toml::toml! {
+                [directory.locations]
+                acme = { symbol = "A acme", style = "bold purple" }
+                src = { symbol = "S" }
+            })
+            .path(&dir)
+            .collect();
+        let expected = Some(format!(
+            "{}A acme{} ",
+            Color::Purple.bold().prefix(),
+            Color::Cyan.paint(convert_path_sep("/src"))
+        ));
+
+        assert_eq!(expected, actual);
+        tmp_dir.close()
+    }
+
+    #[test]
+    fn location_named_after_project_root() -> io::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let project_dir = tmp_dir.path().join("billing");
+        let dir = project_dir.join("src");
+        fs::create_dir_all(&dir)?;
+        fs::write(project_dir.join("Cargo.toml"), "")?;
+
+        let renderer = |root_markers: &[&str]| {
+            let mut config = toml::toml! {
+                [directory]
+                truncation_length = 2
+                [directory.locations]
+                billing = { symbol = "B" }
+            };
+            config["directory"]
+                .as_table_mut()
+                .unwrap()
+                .insert("root_markers".to_string(), root_markers.to_vec().into());
+            ModuleRenderer::new("directory")
+                .config(config)
+                .path(&dir)
+                .collect()
+        };
+
+        assert_eq!(
+            renderer(&["Cargo.toml"]),
+            Some(format!(
+                "{} ",
+                Color::Cyan.bold().paint(convert_path_sep("B/src"))
+            ))
+        );
+        assert_eq!(
+            renderer(&[]),
+            Some(format!(
+                "{} ",
+                Color::Cyan.bold().paint(convert_path_sep("billing/src"))
+            ))
+        );
+        tmp_dir.close()
+    }
+
     #[test]
     #[ignore]
     fn symlinked_git_repo_root() -> io::Result<()> {
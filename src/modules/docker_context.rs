@@ -1,5 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
+use yaml_rust2::YamlLoader;
+
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::docker_context::DockerContextConfig;
@@ -34,12 +38,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let docker_config = PathBuf::from(
+    let docker_config_dir = PathBuf::from(
         &context
             .get_env_os("DOCKER_CONFIG")
             .unwrap_or(context.get_home()?.join(".docker").into_os_string()),
-    )
-    .join("config.json");
+    );
+    let docker_config = docker_config_dir.join("config.json");
 
     let docker_context_env = ["DOCKER_MACHINE_NAME", "DOCKER_HOST", "DOCKER_CONTEXT"]
         .into_iter()
@@ -61,18 +65,33 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
+    let tier = Tier::classify(context, "docker_context", &[Some(&ctx)]);
+    let socket = LazyLock::new(|| {
+        get_engine_socket(context, &docker_config_dir, &ctx, &default_sockets(context))
+    });
+
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
-                "symbol" => Some(config.symbol),
+                "symbol" => Some(tier.symbol_or(config.symbol)),
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
+                "style" => Some(Ok(tier.style_or(config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
-                "context" => Some(Ok(ctx.as_str())),
+                "context" => Some(Ok(ctx.clone())),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name.to_string())),
+                "containers" => {
+                    let project = get_compose_project(context)?;
+                    let count = count_running_containers(context, socket.as_deref()?, &project)?;
+                    Some(Ok(count.to_string()))
+                }
+                "unreachable" => {
+                    let reachable = ping_engine(context, socket.as_deref()?);
+                    (!reachable).then(|| Ok(config.unreachable_symbol.to_string()))
+                }
                 _ => None,
             })
             .parse(None, Some(context))
@@ -88,11 +107,179 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// Finds the unix socket of the Docker Engine API (or the compatible Podman API) for the context.
+///
+/// Like `docker`, `DOCKER_HOST` takes precedence over the endpoint of the context. A context without
+/// metadata has no endpoint unless it's the `default` one, for which Podman's `CONTAINER_HOST` and
+/// `default_sockets` are tried, falling back to the first of them when none exists, so that the
+/// engine is reported as unreachable. Endpoints other than unix sockets, like `tcp://` and
+/// `ssh://`, aren't probed.
+fn get_engine_socket(
+    context: &Context,
+    docker_config_dir: &Path,
+    ctx: &str,
+    default_sockets: &[PathBuf],
+) -> Option<PathBuf> {
+    if let Some(host) = context.get_env("DOCKER_HOST") {
+        return unix_socket_path(&host);
+    }
+
+    if let Some(host) = get_context_endpoint(docker_config_dir, ctx) {
+        return unix_socket_path(&host);
+    }
+
+    if ctx != "default" {
+        return None;
+    }
+
+    if let Some(host) = context.get_env("CONTAINER_HOST") {
+        return unix_socket_path(&host);
+    }
+
+    default_sockets
+        .iter()
+        .find(|path| path.exists())
+        .or(default_sockets.first())
+        .cloned()
+}
+
+/// The usual socket paths of Docker, rootless Docker and Podman
+fn default_sockets(context: &Context) -> Vec<PathBuf> {
+    let runtime_dir = context.get_env("XDG_RUNTIME_DIR").map(PathBuf::from);
+    [
+        Some(PathBuf::from("/var/run/docker.sock")),
+        runtime_dir.as_ref().map(|dir| dir.join("docker.sock")),
+        runtime_dir
+            .as_ref()
+            .map(|dir| dir.join("podman").join("podman.sock")),
+        Some(PathBuf::from("/run/podman/podman.sock")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Reads the Docker endpoint of a context from its metadata in `contexts/meta/<hash>/meta.json`.
+fn get_context_endpoint(docker_config_dir: &Path, ctx: &str) -> Option<String> {
+    std::fs::read_dir(docker_config_dir.join("contexts").join("meta"))
+        .ok()?
+        .filter_map(Result::ok)
+        .find_map(|entry| {
+            let meta = utils::read_file(entry.path().join("meta.json")).ok()?;
+            let meta: serde_json::Value = serde_json::from_str(&meta).ok()?;
+            if meta.get("Name")?.as_str()? != ctx {
+                return None;
+            }
+            Some(
+                meta.get("Endpoints")?
+                    .get("docker")?
+                    .get("Host")?
+                    .as_str()?
+                    .to_owned(),
+            )
+        })
+}
+
+fn unix_socket_path(host: &str) -> Option<PathBuf> {
+    host.strip_prefix("unix://").map(PathBuf::from)
+}
+
+/// Determines the name of the compose project in the current directory the same way `docker
+/// compose` does: `COMPOSE_PROJECT_NAME`, the `name` in the compose file or the directory name.
+fn get_compose_project(context: &Context) -> Option<String> {
+    let compose_file = [
+        "compose.yaml",
+        "compose.yml",
+        "docker-compose.yaml",
+        "docker-compose.yml",
+    ]
+    .iter()
+    .map(|name| context.current_dir.join(name))
+    .find(|path| path.is_file())?;
+
+    if let Some(project) = context.get_env("COMPOSE_PROJECT_NAME") {
+        return Some(project);
+    }
+
+    let name = utils::read_file(&compose_file)
+        .ok()
+        .and_then(|contents| YamlLoader::load_from_str(&contents).ok())
+        .and_then(|docs| docs.first()?["name"].as_str().map(ToOwned::to_owned));
+    let name = match name {
+        Some(name) => name,
+        None => context
+            .current_dir
+            .file_name()?
+            .to_string_lossy()
+            .into_owned(),
+    };
+
+    Some(
+        name.to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect(),
+    )
+}
+
+fn ping_engine(context: &Context, socket: &Path) -> bool {
+    engine_request(context, socket, "/_ping").is_some_and(|body| body == "OK")
+}
+
+fn count_running_containers(context: &Context, socket: &Path, project: &str) -> Option<usize> {
+    let filters = serde_json::json!({ "label": [format!("com.docker.compose.project={project}")] });
+    let path = format!(
+        "/containers/json?filters={}",
+        urlencoding::encode(&filters.to_string())
+    );
+
+    let body = engine_request(context, socket, &path)?;
+    let containers: Vec<serde_json::Value> = serde_json::from_str(&body).ok()?;
+    Some(containers.len())
+}
+
+/// Sends a `GET` request to the Engine API and returns the body of a successful response.
+///
+/// HTTP/1.0 makes the engine close the connection after a response without chunked encoding,
+/// so the response can simply be read to the end.
+#[cfg(unix)]
+fn engine_request(context: &Context, socket: &Path, path: &str) -> Option<String> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    let timeout = context.command_timeout();
+    let mut stream = UnixStream::connect(socket)
+        .map_err(|error| log::debug!("Could not connect to {socket:?}: {error}"))
+        .ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+
+    write!(stream, "GET {path} HTTP/1.0\r\nHost: docker\r\n\r\n").ok()?;
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|error| log::debug!("Could not read response of {path} from {socket:?}: {error}"))
+        .ok()?;
+
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    let status = head.lines().next()?.split_whitespace().nth(1)?;
+    if status != "200" {
+        log::debug!("Request of {path} to {socket:?} failed with status {status}");
+        return None;
+    }
+    Some(body.to_owned())
+}
+
+#[cfg(not(unix))]
+fn engine_request(_context: &Context, _socket: &Path, _path: &str) -> Option<String> {
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::ModuleRenderer;
     use nu_ansi_term::Color;
-    use std::fs::File;
+    use std::fs::{self, File};
     use std::io::{self, Write};
 
     #[test]
@@ -517,4 +704,208 @@ mod tests {
 
         cfg_dir.close()
     }
+
+    #[test]
+    fn test_docker_context_tier() {
+        let actual = ModuleRenderer::new("docker_context")
+            .env("DOCKER_CONTEXT", "prod-swarm")
+            .config(toml::toml! {
+                [environment_tiers.prod]
+                patterns = ["prod-.*"]
+                style = "bold red"
+                [docker_context]
+                only_with_files = false
+                format = "via [$symbol$context \\($tier\\)]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Red.bold().paint("🐳 prod-swarm (prod)")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Creates a Docker config whose current context `colima` points at `socket`
+    fn docker_config_with_context(socket: &std::path::Path) -> io::Result<tempfile::TempDir> {
+        let cfg_dir = tempfile::tempdir()?;
+        fs::write(
+            cfg_dir.path().join("config.json"),
+            r#"{"currentContext": "colima"}"#,
+        )?;
+        let meta_dir = cfg_dir
+            .path()
+            .join("contexts")
+            .join("meta")
+            .join("f24c9d9d");
+        fs::create_dir_all(&meta_dir)?;
+        fs::write(
+            meta_dir.join("meta.json"),
+            serde_json::json!({
+                "Name": "colima",
+                "Metadata": {},
+                "Endpoints": { "docker": { "Host": format!("unix://{}", socket.display()), "SkipTLSVerify": false } }
+            })
+            .to_string(),
+        )?;
+        Ok(cfg_dir)
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_docker_context_compose_containers() -> io::Result<()> {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::net::UnixListener;
+
+        let socket_dir = tempfile::tempdir()?;
+        let socket = socket_dir.path().join("docker.sock");
+        let listener = UnixListener::bind(&socket)?;
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request_line = String::new();
+                BufReader::new(&stream)
+                    .read_line(&mut request_line)
+                    .unwrap();
+                let body = if request_line.starts_with("GET /_ping ") {
+                    "OK"
+                } else if request_line.contains("com.docker.compose.project%3Dshop") {
+                    r#"[{"Id": "a", "State": "running"}, {"Id": "b", "State": "running"}]"#
+                } else {
+                    "[]"
+                };
+                write!(
+                    stream,
+                    "HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{body}"
+                )
+                .unwrap();
+            }
+        });
+
+        let cfg_dir = docker_config_with_context(&socket)?;
+        let pwd = tempfile::tempdir()?;
+        fs::write(
+            pwd.path().join("compose.yaml"),
+            "name: Shop\nservices:\n  web:\n    image: nginx\n",
+        )?;
+
+        let actual = ModuleRenderer::new("docker_context")
+            .env("DOCKER_CONFIG", cfg_dir.path().to_string_lossy())
+            .path(pwd.path())
+            .config(toml::toml! {
+                [docker_context]
+                format = "via [$symbol$context( $containers)( $unreachable)]($style) "
+            })
+            .collect();
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐳 colima 2")));
+
+        assert_eq!(expected, actual);
+        cfg_dir.close()?;
+        pwd.close()?;
+        socket_dir.close()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_docker_context_unreachable_daemon() -> io::Result<()> {
+        let socket_dir = tempfile::tempdir()?;
+        let cfg_dir = docker_config_with_context(&socket_dir.path().join("docker.sock"))?;
+        let pwd = tempfile::tempdir()?;
+        File::create(pwd.path().join("docker-compose.yml"))?;
+
+        let actual = ModuleRenderer::new("docker_context")
+            .env("DOCKER_CONFIG", cfg_dir.path().to_string_lossy())
+            .path(pwd.path())
+            .config(toml::toml! {
+                [docker_context]
+                format = "via [$symbol$context( $containers)( $unreachable)]($style) "
+            })
+            .collect();
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐳 colima ✗")));
+
+        assert_eq!(expected, actual);
+        cfg_dir.close()?;
+        pwd.close()?;
+        socket_dir.close()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_docker_context_without_socket() -> io::Result<()> {
+        let runtime_dir = tempfile::tempdir()?;
+        let cfg_dir = docker_config_with_context(&runtime_dir.path().join("docker.sock"))?;
+        let pwd = tempfile::tempdir()?;
+        File::create(pwd.path().join("compose.yaml"))?;
+
+        let render = |env: &str, value: &str| {
+            ModuleRenderer::new("docker_context")
+                .env("DOCKER_CONFIG", cfg_dir.path().to_string_lossy())
+                .env("XDG_RUNTIME_DIR", runtime_dir.path().to_string_lossy())
+                .env(env, value)
+                .path(pwd.path())
+                .config(toml::toml! {
+                    [docker_context]
+                    format = "via [$symbol$context( $containers)( $unreachable)]($style) "
+                })
+                .collect()
+        };
+
+        // The socket of the context doesn't exist
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐳 colima ✗")));
+        assert_eq!(expected, render("DOCKER_CONTEXT", "colima"));
+
+        // Docker doesn't know the context, so there is no engine to probe
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐳 lima")));
+        assert_eq!(expected, render("DOCKER_CONTEXT", "lima"));
+
+        // Remote endpoints aren't probed
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("🐳 tcp://10.0.0.2:2376")
+        ));
+        assert_eq!(expected, render("DOCKER_HOST", "tcp://10.0.0.2:2376"));
+
+        cfg_dir.close()?;
+        runtime_dir.close()?;
+        pwd.close()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_default_context_sockets() -> io::Result<()> {
+        use super::get_engine_socket;
+        use std::path::PathBuf;
+        let cfg_dir = tempfile::tempdir()?;
+        let runtime_dir = tempfile::tempdir()?;
+        let docker = runtime_dir.path().join("docker.sock");
+        let podman = runtime_dir.path().join("podman.sock");
+        let mut context = crate::test::default_context();
+
+        // The first of the default sockets is reported unreachable when none exists
+        let sockets = [docker.clone(), podman.clone()];
+        assert_eq!(
+            get_engine_socket(&context, cfg_dir.path(), "default", &sockets),
+            Some(docker.clone())
+        );
+        File::create(&podman)?;
+        assert_eq!(
+            get_engine_socket(&context, cfg_dir.path(), "default", &sockets),
+            Some(podman)
+        );
+        // They don't apply to contexts other than the default one
+        assert_eq!(
+            get_engine_socket(&context, cfg_dir.path(), "colima", &sockets),
+            None
+        );
+
+        context
+            .env
+            .insert("CONTAINER_HOST", "unix:///run/user/1000/podman.sock".into());
+        assert_eq!(
+            get_engine_socket(&context, cfg_dir.path(), "default", &sockets),
+            Some(PathBuf::from("/run/user/1000/podman.sock"))
+        );
+
+        cfg_dir.close()?;
+        runtime_dir.close()
+    }
 }
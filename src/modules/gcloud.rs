@@ -4,6 +4,7 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::{LazyLock, OnceLock};
 
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::gcloud::GcloudConfig;
@@ -96,15 +97,24 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
     let gcloud_context = GcloudContext::new(&config_name, &config_path);
     let account: LazyLock<Option<Account<'_>>, _> = LazyLock::new(|| gcloud_context.get_account());
+    let project = context
+        .get_env("CLOUDSDK_CORE_PROJECT")
+        .map(Cow::Owned)
+        .or_else(|| gcloud_context.get_project().map(Cow::Borrowed));
+    let tier = Tier::classify(
+        context,
+        "gcloud",
+        &[project.as_deref(), Some(&gcloud_context.config_name)],
+    );
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
-                "symbol" => Some(config.symbol),
+                "symbol" => Some(tier.symbol_or(config.symbol)),
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
+                "style" => Some(Ok(tier.style_or(config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
@@ -121,10 +131,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     .map(|region| config.region_aliases.get(region).copied().unwrap_or(region))
                     .map(Cow::Borrowed)
                     .map(Ok),
-                "project" => context
-                    .get_env("CLOUDSDK_CORE_PROJECT")
-                    .map(Cow::Owned)
-                    .or_else(|| gcloud_context.get_project().map(Cow::Borrowed))
+                "project" => project
+                    .clone()
                     .map(|project| {
                         config
                             .project_aliases
@@ -134,6 +142,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     })
                     .map(Ok),
                 "active" => Some(Ok(Cow::Borrowed(&gcloud_context.config_name))),
+                "tier" => tier.as_ref().map(|tier| Ok(Cow::Borrowed(tier.name))),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -385,6 +394,43 @@ project = abc
         dir.close()
     }
 
+    #[test]
+    fn project_classified_as_tier() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let active_config_path = dir.path().join("active_config");
+        let mut active_config_file = File::create(active_config_path)?;
+        active_config_file.write_all(b"default")?;
+
+        create_dir(dir.path().join("configurations"))?;
+        let config_default_path = dir.path().join("configurations").join("config_default");
+        let mut config_default_file = File::create(config_default_path)?;
+        config_default_file.write_all(
+            b"\
+[core]
+project = billing-prod
+",
+        )?;
+
+        let actual = ModuleRenderer::new("gcloud")
+            .env("CLOUDSDK_CONFIG", dir.path().to_string_lossy())
+            .config(toml::toml! {
+                [environment_tiers.prod]
+                patterns = [".*-prod"]
+                style = "bold red"
+                symbol = "⚠ "
+                [gcloud]
+                format = "on [$symbol$project \\($tier\\)]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Red.bold().paint("⚠ billing-prod (prod)")
+        ));
+
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
     #[test]
     fn project_set_in_env() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -211,6 +211,9 @@ impl<'a> GitStatusInfo<'a> {
 /// path so the cache is trashed.
 ///
 /// The trashing is only expected when tests run though, as otherwise one path is used with a variety of modules.
+///
+/// A module with a `timeout` only waits that long for another module computing the status, and a
+/// status cut short by its `timeout` is kept to itself.
 pub(crate) fn get_static_repo_status(
     context: &Context,
     repo: &context::Repo,
@@ -218,13 +221,23 @@ pub(crate) fn get_static_repo_status(
 ) -> Option<Arc<RepoStatus>> {
     static REPO_STATUS: parking_lot::Mutex<Option<(Arc<RepoStatus>, PathBuf)>> =
         parking_lot::Mutex::new(None);
-    let mut status = REPO_STATUS.lock();
+    let Some(mut status) = (match context.shared_wait_timeout() {
+        Some(timeout) => REPO_STATUS.try_lock_for(timeout),
+        None => Some(REPO_STATUS.lock()),
+    }) else {
+        log::debug!("Timed out waiting for the git status");
+        return None;
+    };
     let needs_update = status
         .as_ref()
         .is_none_or(|(_status, status_path)| status_path != &context.current_dir);
     if needs_update {
-        *status = get_repo_status(context, repo, config)
-            .map(|status| (Arc::new(status), context.current_dir.clone()));
+        let is_interrupted = Arc::new(AtomicBool::new(false));
+        let repo_status = get_repo_status(context, repo, config, &is_interrupted).map(Arc::new);
+        if is_interrupted.load(std::sync::atomic::Ordering::SeqCst) {
+            return repo_status;
+        }
+        *status = repo_status.map(|status| (status, context.current_dir.clone()));
     }
     status.as_ref().map(|(status, _)| Arc::clone(status))
 }
@@ -234,6 +247,7 @@ fn get_repo_status(
     context: &Context,
     repo: &context::Repo,
     config: &GitStatusConfig,
+    is_interrupted: &Arc<AtomicBool>,
 ) -> Option<RepoStatus> {
     log::debug!("New repo status created");
 
@@ -276,14 +290,12 @@ fn get_repo_status(
             }
         });
     } else {
-        let is_interrupted = Arc::new(AtomicBool::new(false));
         std::thread::Builder::new()
             .name("starship timer".into())
             .stack_size(256 * 1024)
             .spawn({
                 let is_interrupted = is_interrupted.clone();
-                let abort_after =
-                    std::time::Duration::from_millis(context.root_config.command_timeout);
+                let abort_after = context.command_timeout();
                 move || {
                     std::thread::sleep(abort_after);
                     is_interrupted.store(true, std::sync::atomic::Ordering::SeqCst);
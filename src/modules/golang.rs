@@ -1,3 +1,4 @@
+use super::utils::cache::CacheInputs;
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::go::GoConfig;
@@ -10,6 +11,12 @@ use semver::VersionReq;
 use std::ops::Deref;
 use std::sync::LazyLock;
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &["go.mod", "go.work", ".go-version"],
+    env_vars: &["GOTOOLCHAIN", "GOROOT"],
+};
+
 /// Creates a module with the current Go version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("golang");
@@ -1,8 +1,14 @@
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use yaml_rust2::{Yaml, YamlLoader};
+
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::helm::HelmConfig;
 use crate::formatter::StringFormatter;
 use crate::formatter::VersionFormatter;
+use crate::utils;
 
 /// Creates a module with the current Helm version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
@@ -20,6 +26,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
+    let chart = LazyLock::new(|| load_yaml(&context.current_dir.join("Chart.yaml")));
+    let chart_field = |name: &str| Some(chart.as_ref()?[name].as_str()?.to_string());
+
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|var, _| match var {
@@ -44,6 +53,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     )
                     .map(Ok)
                 }
+                "chart_name" => chart_field("name").map(Ok),
+                "chart_version" => chart_field("version").map(Ok),
+                "app_version" => chart_field("appVersion").map(Ok),
+                "unbuilt_dependencies" => {
+                    let unbuilt = count_unbuilt_dependencies(context, chart.as_ref()?);
+                    (unbuilt > 0).then(|| Ok(unbuilt.to_string()))
+                }
+                "environment" => get_helmfile_environment(context).map(Ok),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -60,6 +77,136 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+fn load_yaml(path: &Path) -> Option<Yaml> {
+    let contents = utils::read_file(path).ok()?;
+    YamlLoader::load_from_str(&contents)
+        .ok()?
+        .into_iter()
+        .next()
+}
+
+/// Counts the dependencies of the chart that `helm dependency build` has not put into `charts/`
+/// yet, either as an archive or an unpacked chart.
+///
+/// Charts of `apiVersion: v1` declare their dependencies in `requirements.yaml` instead.
+fn count_unbuilt_dependencies(context: &Context, chart: &Yaml) -> usize {
+    let requirements;
+    let dependencies = match chart["dependencies"].as_vec() {
+        Some(dependencies) => dependencies,
+        None => {
+            requirements = load_yaml(&context.current_dir.join("requirements.yaml"));
+            match requirements
+                .as_ref()
+                .and_then(|requirements| requirements["dependencies"].as_vec())
+            {
+                Some(dependencies) => dependencies,
+                None => return 0,
+            }
+        }
+    };
+
+    let charts_dir = context.current_dir.join("charts");
+    let archives = std::fs::read_dir(&charts_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|name| name.ends_with(".tgz"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    dependencies
+        .iter()
+        .filter_map(|dependency| dependency["name"].as_str().map(|name| (name, dependency)))
+        .filter(|(name, dependency)| {
+            if charts_dir.join(name).join("Chart.yaml").is_file() {
+                return false;
+            }
+            let version = dependency["version"].as_str().unwrap_or_default();
+            !archives
+                .iter()
+                .any(|archive| is_dependency_archive(archive, name, version))
+        })
+        .count()
+}
+
+/// Whether `archive` is the packaged chart `name`. An exact `version` has to match as well, while
+/// a version range matches any version, since it is only resolved when the dependency is built.
+fn is_dependency_archive(archive: &str, name: &str, version: &str) -> bool {
+    let Some(archive_version) = archive
+        .strip_suffix(".tgz")
+        .and_then(|archive| archive.strip_prefix(name)?.strip_prefix('-'))
+    else {
+        return false;
+    };
+
+    match semver::Version::parse(version.trim_start_matches('v')) {
+        Ok(version) => archive_version.trim_start_matches('v') == version.to_string(),
+        Err(_) => archive_version.starts_with(|c: char| c.is_ascii_digit() || c == 'v'),
+    }
+}
+
+/// The environment selected for `helmfile`, which is `default` unless `HELMFILE_ENVIRONMENT` is set.
+///
+/// `helmfile` rejects other environments unless a helmfile declares them under `environments`, so
+/// those are only returned when declared. Helmfiles that can't be read as YAML, like templates
+/// using Go template syntax, can't be checked and accept any environment.
+fn get_helmfile_environment(context: &Context) -> Option<String> {
+    let helmfiles = find_helmfiles(&context.current_dir)?;
+    let environment = context
+        .get_env("HELMFILE_ENVIRONMENT")
+        .unwrap_or_else(|| "default".to_string());
+    if environment == "default" {
+        return Some(environment);
+    }
+
+    let is_declared = helmfiles.iter().any(|helmfile| {
+        let Some(documents) = utils::read_file(helmfile)
+            .ok()
+            .and_then(|contents| YamlLoader::load_from_str(&contents).ok())
+        else {
+            return true;
+        };
+        documents.iter().any(|document| {
+            document["environments"]
+                .as_hash()
+                .is_some_and(|environments| {
+                    environments.contains_key(&Yaml::String(environment.clone()))
+                })
+        })
+    });
+    if !is_declared {
+        log::debug!("helmfile environment {environment:?} is not declared in {helmfiles:?}");
+        return None;
+    }
+    Some(environment)
+}
+
+/// The helmfiles `helmfile` reads in `dir`: `helmfile.yaml`, `helmfile.yaml.gotmpl`, or else
+/// the files in `helmfile.d`.
+fn find_helmfiles(dir: &Path) -> Option<Vec<PathBuf>> {
+    if let Some(helmfile) = ["helmfile.yaml", "helmfile.yaml.gotmpl"]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+    {
+        return Some(vec![helmfile]);
+    }
+
+    let mut helmfiles = std::fs::read_dir(dir.join("helmfile.d"))
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "yaml" || ext == "yml" || ext == "gotmpl")
+        })
+        .collect::<Vec<_>>();
+    helmfiles.sort();
+    (!helmfiles.is_empty()).then_some(helmfiles)
+}
+
 fn parse_helm_version(helm_stdout: &str) -> Option<String> {
     // `helm version --short --client` output looks like this:
     // v3.1.1+gafe7058
@@ -84,7 +231,7 @@ mod tests {
     use super::*;
     use crate::test::ModuleRenderer;
     use nu_ansi_term::Color;
-    use std::fs::File;
+    use std::fs::{self, File};
     use std::io;
 
     #[test]
@@ -129,4 +276,112 @@ mod tests {
         assert_eq!(parse_helm_version(helm_2), Some("2.16.9".to_string()));
         assert_eq!(parse_helm_version(helm_3), Some("3.1.1".to_string()));
     }
+
+    #[test]
+    fn folder_with_chart_metadata() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("Chart.yaml"),
+            "apiVersion: v2\nname: shop\nversion: 1.4.2\nappVersion: \"2.0.1\"\ndependencies:\n  - name: redis\n    version: 18.6.1\n    repository: oci://registry-1.docker.io/bitnamicharts\n  - name: postgresql\n    version: ~13.2.0\n    repository: oci://registry-1.docker.io/bitnamicharts\n  - name: common\n    version: 0.1.0\n    repository: file://../common\n",
+        )?;
+        let charts_dir = dir.path().join("charts");
+        fs::create_dir(&charts_dir)?;
+        // An outdated archive of an exact version does not count
+        File::create(charts_dir.join("redis-18.5.0.tgz"))?;
+        File::create(charts_dir.join("postgresql-13.2.24.tgz"))?;
+        fs::create_dir(charts_dir.join("common"))?;
+        File::create(charts_dir.join("common").join("Chart.yaml"))?;
+
+        let actual = ModuleRenderer::new("helm")
+            .path(dir.path())
+            .config(toml::toml! {
+                [helm]
+                format = "via [$symbol$chart_name@$chart_version \\($app_version\\)( $unbuilt_dependencies unbuilt)]($style)"
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {}",
+            Color::White.bold().paint("⎈ shop@1.4.2 (2.0.1) 1 unbuilt")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_helmfile_environment() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("helmfile.yaml"),
+            "environments:\n  staging:\n    values:\n      - staging.yaml\n---\nreleases:\n  - name: shop\n    chart: ./shop\n",
+        )?;
+
+        let renderer = || {
+            ModuleRenderer::new("helm")
+                .path(dir.path())
+                .config(toml::toml! {
+                    [helm]
+                    format = "[$symbol$environment]($style)"
+                })
+        };
+
+        assert_eq!(
+            renderer().collect(),
+            Some(Color::White.bold().paint("⎈ default").to_string())
+        );
+        assert_eq!(
+            renderer().env("HELMFILE_ENVIRONMENT", "staging").collect(),
+            Some(Color::White.bold().paint("⎈ staging").to_string())
+        );
+        // helmfile rejects environments that aren't declared
+        assert_eq!(
+            renderer().env("HELMFILE_ENVIRONMENT", "prod").collect(),
+            Some(Color::White.bold().paint("⎈ ").to_string())
+        );
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_helmfile_d() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let helmfile_d = dir.path().join("helmfile.d");
+        fs::create_dir(&helmfile_d)?;
+        fs::write(
+            helmfile_d.join("00-infra.yaml"),
+            "environments:\n  prod: {}\n",
+        )?;
+        // Templates may not be valid YAML, so they accept any environment
+        fs::write(
+            helmfile_d.join("10-apps.yaml.gotmpl"),
+            "releases:\n{{ range .Values.apps }}\n  - name: {{ . }}\n{{ end }}\n",
+        )?;
+
+        let actual = ModuleRenderer::new("helm")
+            .path(dir.path())
+            .env("HELMFILE_ENVIRONMENT", "qa")
+            .config(toml::toml! {
+                [helm]
+                format = "[$symbol$environment]($style)"
+            })
+            .collect();
+
+        assert_eq!(actual, Some(Color::White.bold().paint("⎈ qa").to_string()));
+        dir.close()
+    }
+
+    #[test]
+    fn test_is_dependency_archive() {
+        assert!(is_dependency_archive("redis-18.6.1.tgz", "redis", "18.6.1"));
+        assert!(!is_dependency_archive(
+            "redis-18.5.0.tgz",
+            "redis",
+            "18.6.1"
+        ));
+        assert!(is_dependency_archive("redis-18.5.0.tgz", "redis", "^18"));
+        assert!(!is_dependency_archive(
+            "redis-cluster-9.1.0.tgz",
+            "redis",
+            "^9"
+        ));
+    }
 }
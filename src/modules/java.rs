@@ -1,3 +1,4 @@
+use super::utils::cache::CacheInputs;
 use super::{Context, Module, ModuleConfig};
 use crate::configs::java::JavaConfig;
 use crate::formatter::{StringFormatter, VersionFormatter};
@@ -8,6 +9,12 @@ use regex::Regex;
 const JAVA_VERSION_PATTERN: &str =
     "(?:JRE.*\\(|OpenJ9 )(?P<version>\\d+(?:\\.\\d+){0,2}).*, built on";
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[".java-version", ".sdkmanrc"],
+    env_vars: &["JAVA_HOME"],
+};
+
 /// Creates a module with the current Java version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("java");
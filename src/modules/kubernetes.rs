@@ -1,14 +1,22 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use yaml_rust2::{Yaml, YamlLoader};
 
+use chrono::DateTime;
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::kubernetes::KubernetesConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
+use crate::utils::{self, render_time};
 
 #[derive(Default)]
 struct KubeCtxComponents {
@@ -52,6 +60,328 @@ fn get_kube_ctx_components<T: DataValue>(
         })
 }
 
+#[derive(Default)]
+struct KubeUserAuth {
+    /// The exec credential plugin or the auth provider authenticating the user
+    provider: Option<String>,
+    exec_args: Vec<String>,
+    /// The ID token stored in the kubeconfig by the `oidc` auth provider
+    id_token: Option<String>,
+}
+
+fn find_named<'d, T: DataValue>(document: &'d T, list: &str, name: &str) -> Option<&'d T> {
+    document
+        .get(list)?
+        .as_array()?
+        .into_iter()
+        .find(|entry| entry.get("name").and_then(DataValue::as_str) == Some(name))
+}
+
+fn get_kube_cluster_server<T: DataValue>(document: &T, cluster_name: &str) -> Option<String> {
+    find_named(document, "clusters", cluster_name)?
+        .get("cluster")?
+        .get("server")
+        .and_then(DataValue::as_str)
+        .map(String::from)
+}
+
+fn get_kube_user_auth<T: DataValue>(document: &T, user_name: &str) -> Option<KubeUserAuth> {
+    let Some(user) = find_named(document, "users", user_name)?.get("user") else {
+        return Some(KubeUserAuth::default());
+    };
+
+    if let Some(exec) = user.get("exec") {
+        let exec_args: Vec<String> = exec
+            .get("args")
+            .and_then(DataValue::as_array)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(DataValue::as_str)
+            .map(String::from)
+            .collect();
+        let command = exec
+            .get("command")
+            .and_then(DataValue::as_str)
+            .and_then(|command| Path::new(command).file_stem()?.to_str());
+        // kubectl plugins like `kubectl oidc-login` are named by their first argument
+        let provider = match command {
+            Some("kubectl") => exec_args.first().cloned(),
+            command => command.map(String::from),
+        };
+        return Some(KubeUserAuth {
+            provider,
+            exec_args,
+            id_token: None,
+        });
+    }
+
+    let auth_provider = user.get("auth-provider");
+    Some(KubeUserAuth {
+        provider: auth_provider
+            .and_then(|provider| provider.get("name"))
+            .and_then(DataValue::as_str)
+            .map(String::from),
+        exec_args: vec![],
+        id_token: auth_provider
+            .and_then(|provider| provider.get("config"))
+            .and_then(|config| config.get("id-token"))
+            .and_then(DataValue::as_str)
+            .map(String::from),
+    })
+}
+
+/// The host of the API server, e.g. `api.example.com` for `https://api.example.com:6443`
+fn server_host(server: &str) -> &str {
+    let authority = server.split_once("://").map_or(server, |(_, rest)| rest);
+    let authority = authority.split(['/', '?']).next().unwrap_or(authority);
+    if let Some(ipv6) = authority.strip_prefix('[') {
+        return ipv6.split(']').next().unwrap_or(ipv6);
+    }
+    authority.split(':').next().unwrap_or(authority)
+}
+
+/// Returns the expiration date of the credentials of the user as a unix timestamp,
+/// when they are an OIDC ID token or cached by a known exec credential plugin
+fn get_credentials_expiration(
+    context: &Context,
+    auth: &KubeUserAuth,
+    kube_ctx_name: &str,
+) -> Option<i64> {
+    if let Some(id_token) = &auth.id_token {
+        return get_jwt_claims(id_token)?.get("exp")?.as_i64();
+    }
+
+    match auth.provider.as_deref()? {
+        "kubelogin" | "oidc-login" => get_oidc_login_expiration(context, &auth.exec_args),
+        "gke-gcloud-auth-plugin" => get_gke_auth_plugin_expiration(context, kube_ctx_name),
+        _ => None,
+    }
+}
+
+/// Finds the ID token cached by kubelogin for the issuer and client of the exec arguments
+/// https://github.com/int128/kubelogin
+fn get_oidc_login_expiration(context: &Context, exec_args: &[String]) -> Option<i64> {
+    let issuer = get_arg_value(exec_args, "--oidc-issuer-url")?.trim_end_matches('/');
+    let client_id = get_arg_value(exec_args, "--oidc-client-id")?;
+    let cache_dir = match get_arg_value(exec_args, "--token-cache-dir") {
+        Some(dir) => match dir.strip_prefix("~/") {
+            Some(dir) => context.get_home()?.join(dir),
+            None => PathBuf::from(dir),
+        },
+        None => context.get_home()?.join(".kube/cache/oidc-login"),
+    };
+
+    fs::read_dir(cache_dir)
+        .ok()?
+        .filter_map(|entry| utils::read_file(entry.ok()?.path()).ok())
+        .filter_map(|content| {
+            let cache: JsonValue = serde_json::from_str(&content).ok()?;
+            let claims = get_jwt_claims(cache.get("id_token")?.as_str()?)?;
+            let same_issuer = claims.get("iss")?.as_str()?.trim_end_matches('/') == issuer;
+            let same_client = match claims.get("aud")? {
+                JsonValue::String(audience) => audience == client_id,
+                JsonValue::Array(audiences) => audiences
+                    .iter()
+                    .any(|audience| audience.as_str() == Some(client_id)),
+                _ => false,
+            };
+            if !(same_issuer && same_client) {
+                return None;
+            }
+            claims.get("exp")?.as_i64()
+        })
+        .max()
+}
+
+/// Reads the access token cached by the GKE auth plugin, if it was issued for the current context
+fn get_gke_auth_plugin_expiration(context: &Context, kube_ctx_name: &str) -> Option<i64> {
+    let cache_file = context
+        .get_home()?
+        .join(".kube/gke_gcloud_auth_plugin_cache");
+    let cache: JsonValue = serde_json::from_str(&utils::read_file(cache_file).ok()?).ok()?;
+    if cache.get("current_context")?.as_str()? != kube_ctx_name {
+        return None;
+    }
+    let token_expiry = cache.get("token_expiry")?.as_str()?;
+    DateTime::parse_from_rfc3339(token_expiry)
+        .ok()
+        .map(|expiry| expiry.timestamp())
+}
+
+/// Returns the value of an exec argument given either as `--name=value` or `--name value`
+fn get_arg_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == name {
+            return args.get(i + 1).map(String::as_str);
+        }
+        arg.strip_prefix(name)?.strip_prefix('=')
+    })
+}
+
+fn get_jwt_claims(token: &str) -> Option<JsonValue> {
+    let payload = token.split('.').nth(1)?;
+    serde_json::from_slice(&decode_base64url(payload)?).ok()
+}
+
+/// Decodes base64url with or without padding, as used by JSON web tokens
+fn decode_base64url(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for byte in input.trim_end_matches('=').bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(output)
+}
+
+/// How long the version of a cluster is reused before asking the cluster again
+const SERVER_VERSION_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// How long a cluster that didn't answer, e.g. while offline, isn't asked again
+const FAILED_SERVER_VERSION_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The major and minor version of `kubectl` or of a cluster
+#[derive(Clone, Deserialize, Serialize)]
+struct KubeVersion {
+    major: String,
+    minor: u32,
+}
+
+/// The version of the cluster at an API server as cached by the module, `None` if it didn't answer
+#[derive(Deserialize, Serialize)]
+struct CachedServerVersion {
+    version: Option<KubeVersion>,
+    checked: SystemTime,
+}
+
+/// The version of a `kubectl` binary as cached by the module, until the binary is modified
+#[derive(Deserialize, Serialize)]
+struct CachedClientVersion {
+    version: KubeVersion,
+    modified: SystemTime,
+}
+
+/// Returns the difference between the minor versions of `kubectl` and the cluster at `server`,
+/// if it's outside of the version skew supported by Kubernetes
+fn get_version_skew(context: &Context, server: &str) -> Option<String> {
+    let client_version = get_client_version(context)?;
+    let server_version = get_server_version(context, server)?;
+    if client_version.major != server_version.major {
+        return None;
+    }
+    let skew = i64::from(client_version.minor) - i64::from(server_version.minor);
+    (skew.abs() > 1).then(|| format!("{skew:+}"))
+}
+
+/// Returns the version of the `kubectl` in `PATH`, cached per binary in the cache directory
+/// until the binary is modified, e.g. by an upgrade.
+fn get_client_version(context: &Context) -> Option<KubeVersion> {
+    let kubectl =
+        which::which_in("kubectl", context.get_env_os("PATH"), &context.current_dir).ok()?;
+    let modified = fs::metadata(&kubectl).and_then(|m| m.modified()).ok()?;
+    let kubectl = kubectl.to_string_lossy().into_owned();
+
+    let cache_file = context
+        .get_cache_dir()
+        .join("kubernetes/client_versions.json");
+    let mut cache: HashMap<String, CachedClientVersion> = read_version_cache(&cache_file);
+    if let Some(cached) = cache.get(&kubectl) {
+        if cached.modified == modified {
+            return Some(cached.version.clone());
+        }
+    }
+
+    let output = context.exec_cmd("kubectl", &["version", "--client", "--output=json"])?;
+    let versions: JsonValue = serde_json::from_str(&output.stdout).ok()?;
+    let version = parse_kube_version(versions.get("clientVersion")?)?;
+    cache.insert(
+        kubectl,
+        CachedClientVersion {
+            version: version.clone(),
+            modified,
+        },
+    );
+    write_version_cache(&cache_file, &cache);
+    Some(version)
+}
+
+/// Returns the version of the cluster at `server`. Asking the cluster is a network call,
+/// so its answer is cached per server in the cache directory for `SERVER_VERSION_TTL`,
+/// and a cluster that didn't answer isn't asked again for `FAILED_SERVER_VERSION_TTL`.
+fn get_server_version(context: &Context, server: &str) -> Option<KubeVersion> {
+    let cache_file = context
+        .get_cache_dir()
+        .join("kubernetes/server_versions.json");
+    let mut cache: HashMap<String, CachedServerVersion> = read_version_cache(&cache_file);
+    if let Some(cached) = cache.get(server) {
+        let ttl = if cached.version.is_some() {
+            SERVER_VERSION_TTL
+        } else {
+            FAILED_SERVER_VERSION_TTL
+        };
+        if cached.checked.elapsed().is_ok_and(|age| age < ttl) {
+            return cached.version.clone();
+        }
+    }
+
+    let version = context
+        .exec_cmd("kubectl", &["version", "--output=json"])
+        .and_then(|output| {
+            let versions: JsonValue = serde_json::from_str(&output.stdout).ok()?;
+            parse_kube_version(versions.get("serverVersion")?)
+        });
+    cache.insert(
+        server.to_string(),
+        CachedServerVersion {
+            version: version.clone(),
+            checked: SystemTime::now(),
+        },
+    );
+    write_version_cache(&cache_file, &cache);
+    version
+}
+
+/// Reads the versions cached in `cache_file`, none if it's missing or unreadable
+fn read_version_cache<T: DeserializeOwned>(cache_file: &Path) -> HashMap<String, T> {
+    fs::read(cache_file)
+        .ok()
+        .and_then(|content| serde_json::from_slice(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_version_cache<T: Serialize>(cache_file: &Path, cache: &HashMap<String, T>) {
+    let result = serde_json::to_vec(cache)
+        .map_err(std::io::Error::from)
+        .and_then(|content| utils::replace_file(cache_file, &content));
+    if let Err(e) = result {
+        log::debug!("Unable to write the kubectl and cluster versions to {cache_file:?}: {e}");
+    }
+}
+
+/// Parses the major and minor version of a `kubectl version` entry
+fn parse_kube_version(version: &JsonValue) -> Option<KubeVersion> {
+    // Some providers add a suffix, e.g. `27+` on EKS
+    let minor = version.get("minor")?.as_str()?;
+    let minor = minor.trim_end_matches(|c: char| !c.is_ascii_digit());
+    Some(KubeVersion {
+        major: version.get("major")?.as_str()?.to_string(),
+        minor: minor.parse().ok()?,
+    })
+}
+
 fn get_aliased_name<'a>(
     pattern: Option<&'a str>,
     current_value: Option<&str>,
@@ -202,6 +532,23 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             KubeCtxComponents::default()
         });
 
+    let server = ctx_components.cluster.as_deref().and_then(|cluster| {
+        kubeconfigs.iter().find_map(|kubeconfig| match kubeconfig {
+            Document::Json(json) => get_kube_cluster_server(json, cluster),
+            Document::Yaml(yaml) => get_kube_cluster_server(yaml, cluster),
+        })
+    });
+    let user_auth = ctx_components
+        .user
+        .as_deref()
+        .and_then(|user| {
+            kubeconfigs.iter().find_map(|kubeconfig| match kubeconfig {
+                Document::Json(json) => get_kube_user_auth(json, user),
+                Document::Yaml(yaml) => get_kube_user_auth(yaml, user),
+            })
+        })
+        .unwrap_or_default();
+
     // Select the first style that matches the context_pattern and,
     // if it is defined, the user_pattern
     let (matched_context_config, display_context, display_user) = config
@@ -234,12 +581,21 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let display_user =
         display_user.and_then(|user| deprecated::get_alias(user, &config.user_aliases, "user"));
 
+    // The style and symbol of a matching `contexts` entry win over those of the tier
+    let tier = Tier::classify(
+        context,
+        "kubernetes",
+        &[
+            Some(current_kube_ctx_name),
+            ctx_components.cluster.as_deref(),
+        ],
+    );
     let display_style = matched_context_config
         .and_then(|ctx_cfg| ctx_cfg.style)
-        .unwrap_or(config.style);
+        .unwrap_or_else(|| tier.style_or(config.style));
     let display_symbol = matched_context_config
         .and_then(|ctx_cfg| ctx_cfg.symbol)
-        .unwrap_or(config.symbol);
+        .unwrap_or_else(|| tier.symbol_or(config.symbol));
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -264,6 +620,29 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 "user" => display_user
                     .as_ref()
                     .map(|kube_user| Ok(Cow::Borrowed(kube_user.as_str()))),
+                "server" => server
+                    .as_deref()
+                    .map(|server| Ok(Cow::Borrowed(server_host(server)))),
+                "auth_provider" => user_auth
+                    .provider
+                    .as_deref()
+                    .map(|provider| Ok(Cow::Borrowed(provider))),
+                "duration" => {
+                    let expiration =
+                        get_credentials_expiration(context, &user_auth, current_kube_ctx_name)?;
+                    let duration = expiration - chrono::Local::now().timestamp();
+                    Some(Ok(if duration > 0 {
+                        Cow::Owned(render_time((duration * 1000) as u128, false))
+                    } else {
+                        Cow::Borrowed(config.expiration_symbol)
+                    }))
+                }
+                "tier" => tier.as_ref().map(|tier| Ok(Cow::Borrowed(tier.name))),
+                "version_skew" => server
+                    .as_deref()
+                    .and_then(|server| get_version_skew(context, server))
+                    .map(Cow::Owned)
+                    .map(Ok),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -352,10 +731,12 @@ mod tests {
     use crate::modules::kubernetes::Document;
     use crate::modules::kubernetes::parse_kubeconfigs;
     use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
     use nu_ansi_term::Color;
     use std::env;
     use std::fs::{File, create_dir};
     use std::io::{self, Write};
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn test_none_when_disabled() -> io::Result<()> {
@@ -1639,4 +2020,286 @@ users: []
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    fn write_kubeconfig(dir: &std::path::Path, users: &str) -> io::Result<std::path::PathBuf> {
+        let filename = dir.join("config");
+        let mut file = File::create(&filename)?;
+        file.write_all(
+            format!(
+                "
+apiVersion: v1
+clusters:
+  - cluster:
+      server: https://api.prod.example.com:6443/base
+    name: test_cluster
+contexts:
+  - context:
+      cluster: test_cluster
+      user: test_user
+    name: test_context
+current-context: test_context
+kind: Config
+preferences: {{}}
+users:
+  - name: test_user
+    user:
+{users}
+"
+            )
+            .as_bytes(),
+        )?;
+        file.sync_all()?;
+        Ok(filename)
+    }
+
+    #[test]
+    fn test_server_and_exec_auth_provider_with_oidc_login_cache() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let filename = write_kubeconfig(
+            dir.path(),
+            "      exec:
+        apiVersion: client.authentication.k8s.io/v1beta1
+        command: kubectl
+        args:
+          - oidc-login
+          - get-token
+          - --oidc-issuer-url=https://issuer.example.com/
+          - --oidc-client-id
+          - starship",
+        )?;
+
+        // Only the token issued for the client of the kubeconfig is considered
+        let cache_dir = dir.path().join(".kube/cache/oidc-login");
+        std::fs::create_dir_all(&cache_dir)?;
+        std::fs::write(
+            cache_dir.join("a1b2c3"),
+            r#"{"id_token":"eyJhbGciOiJSUzI1NiJ9.eyJpc3MiOiJodHRwczovL2lzc3Vlci5leGFtcGxlLmNvbSIsImF1ZCI6InN0YXJzaGlwIiwiZXhwIjoxMDAwMDAwMDAwfQ.c2ln","refresh_token":"r"}"#,
+        )?;
+        std::fs::write(
+            cache_dir.join("d4e5f6"),
+            r#"{"id_token":"eyJhbGciOiJSUzI1NiJ9.eyJpc3MiOiJodHRwczovL2lzc3Vlci5leGFtcGxlLmNvbSIsImF1ZCI6WyJvdGhlciJdLCJleHAiOjQxMDI0NDQ4MDB9.c2ln"}"#,
+        )?;
+
+        let actual = ModuleRenderer::new("kubernetes")
+            .path(dir.path())
+            .env("HOME", dir.path().to_string_lossy())
+            .env("KUBECONFIG", filename.to_string_lossy().as_ref())
+            .config(toml::toml! {
+                [kubernetes]
+                disabled = false
+                format = "$server $auth_provider( \\[$duration\\])"
+            })
+            .collect();
+
+        let expected = Some("api.prod.example.com oidc-login [X]".to_string());
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn test_oidc_auth_provider_id_token() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let filename = write_kubeconfig(
+            dir.path(),
+            "      auth-provider:
+        name: oidc
+        config:
+          client-id: k8s
+          id-token: eyJhbGciOiJSUzI1NiJ9.eyJpc3MiOiJodHRwczovL2FjY291bnRzLmV4YW1wbGUuY29tIiwiYXVkIjoiazhzIiwiZXhwIjoxMDAwMDAwMDAwfQ.c2ln",
+        )?;
+
+        let actual = ModuleRenderer::new("kubernetes")
+            .path(dir.path())
+            .env("KUBECONFIG", filename.to_string_lossy().as_ref())
+            .config(toml::toml! {
+                [kubernetes]
+                disabled = false
+                format = "$auth_provider( \\[$duration\\])"
+                expiration_symbol = "expired"
+            })
+            .collect();
+
+        let expected = Some("oidc [expired]".to_string());
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn test_gke_auth_plugin_cache_duration() -> io::Result<()> {
+        use chrono::{DateTime, SecondsFormat, Utc};
+
+        let dir = tempfile::tempdir()?;
+        let filename = write_kubeconfig(
+            dir.path(),
+            "      exec:
+        apiVersion: client.authentication.k8s.io/v1beta1
+        command: /usr/lib/google-cloud-sdk/bin/gke-gcloud-auth-plugin",
+        )?;
+
+        let now_plus_half_hour: DateTime<Utc> =
+            DateTime::from_timestamp(chrono::Local::now().timestamp() + 1800, 0).unwrap();
+        let cache = serde_json::json!({
+            "current_context": "test_context",
+            "access_token": "token",
+            "token_expiry": now_plus_half_hour.to_rfc3339_opts(SecondsFormat::Secs, true),
+        });
+        std::fs::create_dir_all(dir.path().join(".kube"))?;
+        std::fs::write(
+            dir.path().join(".kube/gke_gcloud_auth_plugin_cache"),
+            cache.to_string(),
+        )?;
+
+        let actual = ModuleRenderer::new("kubernetes")
+            .path(dir.path())
+            .env("HOME", dir.path().to_string_lossy())
+            .env("KUBECONFIG", filename.to_string_lossy().as_ref())
+            .config(toml::toml! {
+                [kubernetes]
+                disabled = false
+                format = "$auth_provider( \\[$duration\\])"
+            })
+            .collect();
+
+        let possible_values = [
+            "30m2s", "30m1s", "30m0s", "29m59s", "29m58s", "29m57s", "29m56s", "29m55s",
+        ]
+        .map(|duration| Some(format!("gke-gcloud-auth-plugin [{duration}]")));
+        assert!(
+            possible_values.contains(&actual),
+            "time is not in range: {actual:?}"
+        );
+        dir.close()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_version_skew() -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir()?;
+        let filename = write_kubeconfig(dir.path(), "      token: secret")?;
+        // The client version is cached for the `kubectl` found in `PATH`
+        let bin = tempfile::tempdir()?;
+        let kubectl = File::create(bin.path().join("kubectl"))?;
+        let mut permissions = kubectl.metadata()?.permissions();
+        permissions.set_mode(0o700);
+        kubectl.set_permissions(permissions)?;
+
+        let render = |cache: &std::path::Path, client_minor: &str, server_minor: Option<&str>| {
+            ModuleRenderer::new("kubernetes")
+                .path(dir.path())
+                .env("KUBECONFIG", filename.to_string_lossy().as_ref())
+                .env("PATH", bin.path().to_string_lossy())
+                .env("STARSHIP_CACHE", cache.to_string_lossy())
+                .config(toml::toml! {
+                    [kubernetes]
+                    disabled = false
+                    format = "$context( \\(skew $version_skew\\))"
+                })
+                .cmd(
+                    "kubectl version --client --output=json",
+                    Some(CommandOutput {
+                        stdout: format!(r#"{{"clientVersion":{{"major":"1","minor":"{client_minor}"}}}}"#),
+                        stderr: String::new(),
+                    }),
+                )
+                .cmd(
+                    "kubectl version --output=json",
+                    server_minor.map(|server_minor| CommandOutput {
+                        stdout: format!(
+                            r#"{{"clientVersion":{{"major":"1","minor":"{client_minor}"}},"serverVersion":{{"major":"1","minor":"{server_minor}"}}}}"#
+                        ),
+                        stderr: String::new(),
+                    }),
+                )
+                .collect()
+        };
+
+        let cache = tempfile::tempdir()?;
+        assert_eq!(
+            render(cache.path(), "30", Some("27+")),
+            Some("test_context (skew +3)".to_string())
+        );
+        // Neither kubectl nor the cluster are asked again while their versions are cached
+        assert_eq!(
+            render(cache.path(), "31", Some("29")),
+            Some("test_context (skew +3)".to_string())
+        );
+        // Until kubectl is upgraded
+        kubectl.set_modified(SystemTime::now() + Duration::from_secs(60))?;
+        assert_eq!(
+            render(cache.path(), "31", Some("29")),
+            Some("test_context (skew +4)".to_string())
+        );
+        cache.close()?;
+
+        // kubectl supports one minor version of skew
+        let cache = tempfile::tempdir()?;
+        assert_eq!(
+            render(cache.path(), "30", Some("29")),
+            Some("test_context".to_string())
+        );
+        cache.close()?;
+
+        // A cluster that didn't answer isn't asked again right away
+        let cache = tempfile::tempdir()?;
+        assert_eq!(
+            render(cache.path(), "30", None),
+            Some("test_context".to_string())
+        );
+        assert_eq!(
+            render(cache.path(), "30", Some("27")),
+            Some("test_context".to_string())
+        );
+        cache.close()?;
+        bin.close()?;
+        dir.close()
+    }
+
+    #[test]
+    fn test_tier() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let filename = write_kubeconfig(dir.path(), "      token: secret")?;
+
+        let render = |contexts: toml::Value| {
+            let mut config = toml::toml! {
+                [environment_tiers.prod]
+                patterns = ["test_cluster"]
+                style = "bold red"
+                symbol = "! "
+                [kubernetes]
+                disabled = false
+                format = "[$symbol$context \\($tier\\)]($style)"
+            };
+            config["kubernetes"]
+                .as_table_mut()
+                .unwrap()
+                .insert("contexts".to_string(), contexts);
+            ModuleRenderer::new("kubernetes")
+                .path(dir.path())
+                .env("KUBECONFIG", filename.to_string_lossy().as_ref())
+                .config(config)
+                .collect()
+        };
+
+        // The tier matches the cluster of the context
+        assert_eq!(
+            render(toml::Value::Array(vec![])),
+            Some(format!(
+                "{}",
+                Color::Red.bold().paint("! test_context (prod)")
+            ))
+        );
+        // A matching `contexts` entry still sets its own style
+        assert_eq!(
+            render(toml::Value::Array(vec![
+                toml::toml! {
+                    context_pattern = "test_context"
+                    style = "green"
+                }
+                .into()
+            ])),
+            Some(format!("{}", Color::Green.paint("! test_context (prod)")))
+        );
+        dir.close()
+    }
 }
@@ -10,6 +10,8 @@ mod cmake;
 mod cmd_duration;
 mod cobol;
 mod conda;
+mod config_value;
+mod consul;
 mod container;
 mod cpp;
 mod crystal;
@@ -62,6 +64,7 @@ mod netns;
 mod nim;
 mod nix_shell;
 mod nodejs;
+mod nomad;
 mod ocaml;
 mod odin;
 mod opa;
@@ -82,6 +85,7 @@ mod rlang;
 mod ruby;
 mod rust;
 mod scala;
+mod session;
 mod shell;
 mod shlvl;
 mod singularity;
@@ -95,6 +99,7 @@ mod time;
 mod username;
 mod utils;
 mod vagrant;
+mod vault;
 mod vcsh;
 mod vlang;
 mod xmake;
@@ -107,132 +112,34 @@ mod typst;
 #[cfg(feature = "battery")]
 pub use self::battery::{BatteryInfoProvider, BatteryInfoProviderImpl};
 
+use self::utils::cache::{self, CacheInputs};
 use crate::config::ModuleConfig;
 use crate::context::{Context, Shell};
 use crate::module::Module;
 use std::time::Instant;
 
 pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
+    // The prompt is unusable without the modules that end it, so those are never skipped
+    if context.is_prompt_budget_exceeded() && !matches!(module, "character" | "line_break" | "fill")
+    {
+        log::warn!(
+            "Skipping module {module:?}, the prompt took longer than prompt_budget ({}ms)",
+            context.root_config.prompt_budget
+        );
+        return None;
+    }
+
+    // Modules like `custom.foo` aren't checked by `is_module_disabled_in_config` before
+    if context.is_module_disabled_on_slow_fs(module) {
+        return None;
+    }
+
     let start: Instant = Instant::now();
-    let mut m: Option<Module> = {
-        match module {
-            // Keep these ordered alphabetically.
-            // Default ordering is handled in configs/starship_root.rs
-            "aws" => aws::module(context),
-            "azure" => azure::module(context),
-            #[cfg(feature = "battery")]
-            "battery" => battery::module(context),
-            "buf" => buf::module(context),
-            "bun" => bun::module(context),
-            "c" => c::module(context),
-            "character" => character::module(context),
-            "cmake" => cmake::module(context),
-            "cmd_duration" => cmd_duration::module(context),
-            "cobol" => cobol::module(context),
-            "conda" => conda::module(context),
-            "container" => container::module(context),
-            "cpp" => cpp::module(context),
-            "daml" => daml::module(context),
-            "dart" => dart::module(context),
-            "deno" => deno::module(context),
-            "directory" => directory::module(context),
-            "direnv" => direnv::module(context),
-            "docker_context" => docker_context::module(context),
-            "dotnet" => dotnet::module(context),
-            "elixir" => elixir::module(context),
-            "elm" => elm::module(context),
-            "erlang" => erlang::module(context),
-            "env_var" => env_var::module(None, context),
-            "fennel" => fennel::module(context),
-            "fill" => fill::module(context),
-            "fossil_branch" => fossil_branch::module(context),
-            "fossil_metrics" => fossil_metrics::module(context),
-            "gcloud" => gcloud::module(context),
-            "git_branch" => git_branch::module(context),
-            "git_commit" => git_commit::module(context),
-            "git_metrics" => git_metrics::module(context),
-            "git_state" => git_state::module(context),
-            "git_status" => git_status::module(context),
-            "gleam" => gleam::module(context),
-            "golang" => golang::module(context),
-            "gradle" => gradle::module(context),
-            "guix_shell" => guix_shell::module(context),
-            "haskell" => haskell::module(context),
-            "haxe" => haxe::module(context),
-            "helm" => helm::module(context),
-            "hg_branch" => hg_branch::module(context),
-            "hg_state" => hg_state::module(context),
-            "hostname" => hostname::module(context),
-            "java" => java::module(context),
-            "jobs" => jobs::module(context),
-            "julia" => julia::module(context),
-            "kotlin" => kotlin::module(context),
-            "kubernetes" => kubernetes::module(context),
-            "line_break" => line_break::module(context),
-            "localip" => localip::module(context),
-            "lua" => lua::module(context),
-            "memory_usage" => memory_usage::module(context),
-            "meson" => meson::module(context),
-            "mise" => mise::module(context),
-            "mojo" => mojo::module(context),
-            "nats" => nats::module(context),
-            "netns" => netns::module(context),
-            "nim" => nim::module(context),
-            "nix_shell" => nix_shell::module(context),
-            "nodejs" => nodejs::module(context),
-            "ocaml" => ocaml::module(context),
-            "odin" => odin::module(context),
-            "opa" => opa::module(context),
-            "openstack" => openstack::module(context),
-            "os" => os::module(context),
-            "package" => package::module(context),
-            "perl" => perl::module(context),
-            "php" => php::module(context),
-            "pijul_channel" => pijul_channel::module(context),
-            "pixi" => pixi::module(context),
-            "pulumi" => pulumi::module(context),
-            "purescript" => purescript::module(context),
-            "python" => python::module(context),
-            "quarto" => quarto::module(context),
-            "raku" => raku::module(context),
-            "rlang" => rlang::module(context),
-            "red" => red::module(context),
-            "ruby" => ruby::module(context),
-            "rust" => rust::module(context),
-            "scala" => scala::module(context),
-            "shell" => shell::module(context),
-            "shlvl" => shlvl::module(context),
-            "singularity" => singularity::module(context),
-            "solidity" => solidity::module(context),
-            "spack" => spack::module(context),
-            "swift" => swift::module(context),
-            "status" => status::module(context),
-            "sudo" => sudo::module(context),
-            "terraform" => terraform::module(context),
-            "time" => time::module(context),
-            "typst" => typst::module(context),
-            "crystal" => crystal::module(context),
-            "username" => username::module(context),
-            "vlang" => vlang::module(context),
-            "vagrant" => vagrant::module(context),
-            "vcsh" => vcsh::module(context),
-            "xmake" => xmake::module(context),
-            "zig" => zig::module(context),
-            env if env.starts_with("env_var.") => {
-                env_var::module(env.strip_prefix("env_var."), context)
-            }
-            custom if custom.starts_with("custom.") => {
-                // SAFETY: We just checked that the module starts with "custom."
-                custom::module(custom.strip_prefix("custom.").unwrap(), context)
-            }
-            _ => {
-                eprintln!(
-                    "Error: Unknown module {module}. Use starship module --list to list out all supported modules."
-                );
-                None
-            }
-        }
-    };
+    let mut m: Option<Module> =
+        context.with_module_timeout(module, || match cache_inputs(module) {
+            Some(inputs) => cache::memoize(module, inputs, context, || compute(module, context)),
+            None => compute(module, context),
+        });
 
     let elapsed = start.elapsed();
     log::trace!("Took {elapsed:?} to compute module {module:?}");
@@ -246,6 +153,150 @@ pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
     m
 }
 
+fn compute<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
+    match module {
+        // Keep these ordered alphabetically.
+        // Default ordering is handled in configs/starship_root.rs
+        "aws" => aws::module(context),
+        "azure" => azure::module(context),
+        #[cfg(feature = "battery")]
+        "battery" => battery::module(context),
+        "buf" => buf::module(context),
+        "bun" => bun::module(context),
+        "c" => c::module(context),
+        "character" => character::module(context),
+        "cmake" => cmake::module(context),
+        "cmd_duration" => cmd_duration::module(context),
+        "cobol" => cobol::module(context),
+        "conda" => conda::module(context),
+        "consul" => consul::module(context),
+        "container" => container::module(context),
+        "cpp" => cpp::module(context),
+        "daml" => daml::module(context),
+        "dart" => dart::module(context),
+        "deno" => deno::module(context),
+        "directory" => directory::module(context),
+        "direnv" => direnv::module(context),
+        "docker_context" => docker_context::module(context),
+        "dotnet" => dotnet::module(context),
+        "elixir" => elixir::module(context),
+        "elm" => elm::module(context),
+        "erlang" => erlang::module(context),
+        "env_var" => env_var::module(None, context),
+        "fennel" => fennel::module(context),
+        "fill" => fill::module(context),
+        "fossil_branch" => fossil_branch::module(context),
+        "fossil_metrics" => fossil_metrics::module(context),
+        "gcloud" => gcloud::module(context),
+        "git_branch" => git_branch::module(context),
+        "git_commit" => git_commit::module(context),
+        "git_metrics" => git_metrics::module(context),
+        "git_state" => git_state::module(context),
+        "git_status" => git_status::module(context),
+        "gleam" => gleam::module(context),
+        "golang" => golang::module(context),
+        "gradle" => gradle::module(context),
+        "guix_shell" => guix_shell::module(context),
+        "haskell" => haskell::module(context),
+        "haxe" => haxe::module(context),
+        "helm" => helm::module(context),
+        "hg_branch" => hg_branch::module(context),
+        "hg_state" => hg_state::module(context),
+        "hostname" => hostname::module(context),
+        "java" => java::module(context),
+        "jobs" => jobs::module(context),
+        "julia" => julia::module(context),
+        "kotlin" => kotlin::module(context),
+        "kubernetes" => kubernetes::module(context),
+        "line_break" => line_break::module(context),
+        "localip" => localip::module(context),
+        "lua" => lua::module(context),
+        "memory_usage" => memory_usage::module(context),
+        "meson" => meson::module(context),
+        "mise" => mise::module(context),
+        "mojo" => mojo::module(context),
+        "nats" => nats::module(context),
+        "netns" => netns::module(context),
+        "nim" => nim::module(context),
+        "nix_shell" => nix_shell::module(context),
+        "nodejs" => nodejs::module(context),
+        "nomad" => nomad::module(context),
+        "ocaml" => ocaml::module(context),
+        "odin" => odin::module(context),
+        "opa" => opa::module(context),
+        "openstack" => openstack::module(context),
+        "os" => os::module(context),
+        "package" => package::module(context),
+        "perl" => perl::module(context),
+        "php" => php::module(context),
+        "pijul_channel" => pijul_channel::module(context),
+        "pixi" => pixi::module(context),
+        "pulumi" => pulumi::module(context),
+        "purescript" => purescript::module(context),
+        "python" => python::module(context),
+        "quarto" => quarto::module(context),
+        "raku" => raku::module(context),
+        "rlang" => rlang::module(context),
+        "red" => red::module(context),
+        "ruby" => ruby::module(context),
+        "rust" => rust::module(context),
+        "scala" => scala::module(context),
+        "session" => session::module(context),
+        "shell" => shell::module(context),
+        "shlvl" => shlvl::module(context),
+        "singularity" => singularity::module(context),
+        "solidity" => solidity::module(context),
+        "spack" => spack::module(context),
+        "swift" => swift::module(context),
+        "status" => status::module(context),
+        "sudo" => sudo::module(context),
+        "terraform" => terraform::module(context),
+        "time" => time::module(context),
+        "typst" => typst::module(context),
+        "crystal" => crystal::module(context),
+        "username" => username::module(context),
+        "vlang" => vlang::module(context),
+        "vagrant" => vagrant::module(context),
+        "vault" => vault::module(context),
+        "vcsh" => vcsh::module(context),
+        "xmake" => xmake::module(context),
+        "zig" => zig::module(context),
+        env if env.starts_with("env_var.") => {
+            env_var::module(env.strip_prefix("env_var."), context)
+        }
+        config_value if config_value.starts_with("config_value.") => {
+            // SAFETY: We just checked that the module starts with "config_value."
+            config_value::module(config_value.strip_prefix("config_value.").unwrap(), context)
+        }
+        custom if custom.starts_with("custom.") => {
+            // SAFETY: We just checked that the module starts with "custom."
+            custom::module(custom.strip_prefix("custom.").unwrap(), context)
+        }
+        _ => {
+            eprintln!(
+                "Error: Unknown module {module}. Use starship module --list to list out all supported modules."
+            );
+            None
+        }
+    }
+}
+
+/// The inputs of the modules whose output is cached between prompts
+fn cache_inputs(module: &str) -> Option<&'static CacheInputs> {
+    match module {
+        "bun" => Some(&bun::CACHE_INPUTS),
+        "deno" => Some(&deno::CACHE_INPUTS),
+        "golang" => Some(&golang::CACHE_INPUTS),
+        "java" => Some(&java::CACHE_INPUTS),
+        "nodejs" => Some(&nodejs::CACHE_INPUTS),
+        "php" => Some(&php::CACHE_INPUTS),
+        "python" => Some(&python::CACHE_INPUTS),
+        "ruby" => Some(&ruby::CACHE_INPUTS),
+        "rust" => Some(&rust::CACHE_INPUTS),
+        _ => None,
+    }
+}
+
 pub fn description(module: &str) -> &'static str {
     match module {
         "aws" => "The current AWS region and profile",
@@ -261,6 +312,7 @@ pub fn description(module: &str) -> &'static str {
         "cmd_duration" => "How long the last command took to execute",
         "cobol" => "The currently installed version of COBOL/GNUCOBOL",
         "conda" => "The current conda environment, if $CONDA_DEFAULT_ENV is set",
+        "consul" => "The current Consul address and namespace",
         "container" => "The container indicator, if inside a container.",
         "cpp" => "your cpp compiler type",
         "crystal" => "The currently installed version of Crystal",
@@ -313,6 +365,7 @@ pub fn description(module: &str) -> &'static str {
         "nim" => "The currently installed version of Nim",
         "nix_shell" => "The nix-shell environment",
         "nodejs" => "The currently installed version of NodeJS",
+        "nomad" => "The current Nomad address, namespace and region",
         "ocaml" => "The currently installed version of OCaml",
         "odin" => "The currently installed version of Odin",
         "opa" => "The currently installed version of Open Platform Agent",
@@ -335,6 +388,7 @@ pub fn description(module: &str) -> &'static str {
         "ruby" => "The currently installed version of Ruby",
         "rust" => "The currently installed version of Rust",
         "scala" => "The currently installed version of Scala",
+        "session" => "The current remote session and terminal multiplexer",
         "shell" => "The currently used shell indicator",
         "shlvl" => "The current value of SHLVL",
         "singularity" => "The currently used Singularity image",
@@ -348,6 +402,7 @@ pub fn description(module: &str) -> &'static str {
         "typst" => "The current installed version of typst",
         "username" => "The active user's username",
         "vagrant" => "The currently installed version of Vagrant",
+        "vault" => "The current Vault address and namespace",
         "vcsh" => "The currently active VCSH repository",
         "vlang" => "The currently installed version of V",
         "xmake" => "The currently installed version of XMake",
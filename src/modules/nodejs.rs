@@ -1,3 +1,4 @@
+use super::utils::cache::CacheInputs;
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::nodejs::NodejsConfig;
@@ -10,6 +11,12 @@ use serde_json as json;
 use std::ops::Deref;
 use std::sync::LazyLock;
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &["package.json", ".nvmrc", ".node-version"],
+    env_vars: &["NVM_BIN", "VOLTA_HOME", "FNM_MULTISHELL_PATH"],
+};
+
 /// Creates a module with the current Node.js version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("nodejs");
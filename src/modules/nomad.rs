@@ -0,0 +1,134 @@
+use super::utils::hashicorp::{alias, display_address};
+use super::utils::tier::{Tier, TierOverride};
+use super::{Context, Module, ModuleConfig};
+
+use crate::configs::nomad::NomadConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current Nomad address, namespace and region
+///
+/// Will display the Nomad address if `NOMAD_ADDR` is set, and the namespace and region from
+/// `NOMAD_NAMESPACE` and `NOMAD_REGION`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("nomad");
+    let config: NomadConfig = NomadConfig::try_load(module.config);
+
+    if !(context.detect_env_vars(&config.detect_env_vars)) {
+        return None;
+    }
+
+    let address = context.get_env("NOMAD_ADDR")?;
+    let namespace = context.get_env("NOMAD_NAMESPACE");
+    let region = context.get_env("NOMAD_REGION");
+
+    let tier = Tier::classify(context, "nomad", &[namespace.as_deref(), Some(&address)]);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(tier.symbol_or(config.symbol)),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(tier.style_or(config.style))),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "address" => Some(Ok(alias(
+                    &config.address_aliases,
+                    display_address(&address),
+                ))),
+                "namespace" => namespace
+                    .as_deref()
+                    .map(|namespace| Ok(alias(&config.namespace_aliases, namespace))),
+                "region" => region
+                    .as_deref()
+                    .map(|region| Ok(alias(&config.region_aliases, region))),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name)),
+                _ => None,
+            })
+            .parse(None, Some(context))
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `nomad`:\n{error}");
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use nu_ansi_term::Color;
+
+    #[test]
+    fn not_set() {
+        let actual = ModuleRenderer::new("nomad")
+            .env("NOMAD_REGION", "global")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn address_namespace_and_region() {
+        let actual = ModuleRenderer::new("nomad")
+            .env("NOMAD_ADDR", "https://nomad.example.com:4646")
+            .env("NOMAD_NAMESPACE", "billing")
+            .env("NOMAD_REGION", "eu-west")
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green
+                .bold()
+                .paint("🏕️  nomad.example.com:4646/billing (eu-west)")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn aliases() {
+        let actual = ModuleRenderer::new("nomad")
+            .env("NOMAD_ADDR", "https://nomad.example.com:4646")
+            .env("NOMAD_REGION", "eu-west")
+            .config(toml::toml! {
+                [nomad]
+                [nomad.address_aliases]
+                "nomad.example.com:4646" = "prod"
+                [nomad.region_aliases]
+                "eu-west" = "euw"
+            })
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("🏕️  prod (euw)")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn detect_env_vars() {
+        let renderer = || {
+            ModuleRenderer::new("nomad")
+                .env("NOMAD_ADDR", "http://127.0.0.1:4646")
+                .config(toml::toml! {
+                    [nomad]
+                    format = "$address"
+                    detect_env_vars = ["NOMAD_PROMPT"]
+                })
+        };
+
+        assert_eq!(None, renderer().collect());
+        assert_eq!(
+            Some("127.0.0.1:4646".to_string()),
+            renderer().env("NOMAD_PROMPT", "1").collect()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use yaml_rust2::YamlLoader;
 
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::openstack::OspConfig;
@@ -53,20 +54,26 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let (osp_cloud, osp_project) = get_osp_cloud_and_project(context);
 
     osp_cloud.as_ref()?;
+    let tier = Tier::classify(
+        context,
+        "openstack",
+        &[osp_cloud.as_deref(), osp_project.as_deref()],
+    );
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
-                "symbol" => Some(config.symbol),
+                "symbol" => Some(tier.symbol_or(config.symbol)),
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
+                "style" => Some(Ok(tier.style_or(config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
-                "cloud" => osp_cloud.as_ref().map(Ok),
-                "project" => osp_project.as_ref().map(Ok),
+                "cloud" => osp_cloud.as_deref().map(Ok),
+                "project" => osp_project.as_deref().map(Ok),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name)),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -164,4 +171,25 @@ dummy_yaml
         assert_eq!(actual, expected);
         dir.close()
     }
+
+    #[test]
+    fn cloud_classified_as_tier() {
+        let actual = ModuleRenderer::new("openstack")
+            .env("OS_CLOUD", "corp-prod")
+            .env("OS_PROJECT_NAME", "billing")
+            .config(toml::toml! {
+                [environment_tiers.prod]
+                patterns = [".*-prod"]
+                style = "bold red"
+                [openstack]
+                format = "on [$symbol$cloud( \\[$tier\\])]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Red.bold().paint("☁️  corp-prod [prod]")
+        ));
+
+        assert_eq!(actual, expected);
+    }
 }
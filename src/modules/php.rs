@@ -1,9 +1,16 @@
 use super::{Context, Module, ModuleConfig};
 
+use super::utils::cache::CacheInputs;
 use crate::configs::php::PhpConfig;
 use crate::formatter::StringFormatter;
 use crate::formatter::VersionFormatter;
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[".php-version"],
+    env_vars: &[],
+};
+
 /// Creates a module with the current PHP version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("php");
@@ -7,8 +7,10 @@ use std::fs::File;
 use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::LazyLock;
 use yaml_rust2::{Yaml, YamlLoader};
 
+use super::utils::tier::{Tier, TierOverride};
 use super::{Context, Module, ModuleConfig};
 use crate::configs::pulumi::PulumiConfig;
 use crate::formatter::{StringFormatter, VersionFormatter};
@@ -41,15 +43,22 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 
     let project_file = find_package_file(&context.logical_dir)?;
+    let project = load_yaml(&project_file);
+    let stack = project
+        .as_ref()
+        .and_then(|project| stack_name(project, &project_file, context));
+    let stack_config_file =
+        LazyLock::new(|| stack_config_file(project.as_ref()?, &project_file, stack.as_deref()?));
+    let tier = Tier::classify(context, "pulumi", &[stack.as_deref()]);
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
-                "symbol" => Some(config.symbol),
+                "symbol" => Some(tier.symbol_or(config.symbol)),
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(config.style)),
+                "style" => Some(Ok(tier.style_or(config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
@@ -63,7 +72,16 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 }
                 .map(Ok),
                 "username" => get_pulumi_username(context).map(Ok),
-                "stack" => stack_name(&project_file, context).map(Ok),
+                "stack" => stack.clone().map(Ok),
+                "backend" => get_pulumi_backend(context, project.as_ref()).map(Ok),
+                "missing_config" => (stack.is_some() && stack_config_file.is_none())
+                    .then(|| Ok(config.missing_config_symbol.to_string())),
+                "secrets_provider" => stack_config_file
+                    .as_deref()
+                    .and_then(load_yaml)
+                    .and_then(|stack_config| get_secrets_provider(&stack_config))
+                    .map(Ok),
+                "tier" => tier.as_ref().map(|tier| Ok(tier.name.to_string())),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -121,28 +139,29 @@ fn find_package_file(path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Load the first document of a YAML file, like `Pulumi.yaml` or `Pulumi.<stack>.yaml`.
+fn load_yaml(path: &Path) -> Option<Yaml> {
+    let mut file = File::open(path).ok()?;
+
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).ok()?;
+    let yaml = YamlLoader::load_from_str(&contents)
+        .ok()?
+        .into_iter()
+        .next()?;
+    log::trace!("Parsed {path:?} into yaml");
+    Some(yaml)
+}
+
 /// We get the name of the current stack.
 ///
 /// Pulumi has no CLI option that is fast enough to get this for us, but finding
 /// the location is simple. We get it ourselves.
-fn stack_name(project_file: &Path, context: &Context) -> Option<String> {
-    let mut file = File::open(project_file).ok()?;
-
-    let mut contents = String::new();
-    file.read_to_string(&mut contents).ok()?;
-    let name = YamlLoader::load_from_str(&contents).ok().and_then(
-        |yaml| -> Option<Option<String>> {
-            log::trace!("Parsed {project_file:?} into yaml");
-            let yaml = yaml.into_iter().next()?;
-            yaml.into_hash().map(|mut hash| -> Option<String> {
-                hash.remove(&Yaml::String("name".to_string()))?
-                    .into_string()
-            })
-        },
-    )??;
+fn stack_name(project: &Yaml, project_file: &Path, context: &Context) -> Option<String> {
+    let name = project["name"].as_str()?;
     log::trace!("Found project name: {name:?}");
 
-    let workspace_file = get_pulumi_workspace(context, &name, project_file)
+    let workspace_file = get_pulumi_workspace(context, name, project_file)
         .map(File::open)?
         .ok()?;
     log::trace!("Trying to read workspace_file: {workspace_file:?}");
@@ -189,15 +208,71 @@ fn pulumi_home_dir(context: &Context) -> Option<PathBuf> {
     }
 }
 
-fn get_pulumi_username(context: &Context) -> Option<String> {
+/// Find the configuration file of a stack, which lives next to `Pulumi.yaml` unless the project
+/// sets `stackConfigDir`.
+///
+/// The file is named after the stack only, without the organization and project a fully
+/// qualified stack name like `acme/website/dev` includes.
+fn stack_config_file(project: &Yaml, project_file: &Path, stack: &str) -> Option<PathBuf> {
+    let mut dir = project_file.parent()?.to_path_buf();
+    if let Some(stack_config_dir) = project["stackConfigDir"].as_str() {
+        dir.push(stack_config_dir);
+    }
+    let stack = stack.rsplit('/').next()?;
+
+    ["yaml", "yml"]
+        .iter()
+        .map(|extension| dir.join(format!("Pulumi.{stack}.{extension}")))
+        .find(|path| path.is_file())
+}
+
+/// Get the secrets provider of a stack from its configuration file.
+///
+/// Stacks using a passphrase only store the salt, and stacks using the Pulumi Cloud store
+/// neither a provider nor a salt.
+fn get_secrets_provider(stack_config: &Yaml) -> Option<String> {
+    let provider = match stack_config["secretsprovider"].as_str() {
+        Some(provider) => url_scheme(provider).unwrap_or(provider),
+        None if stack_config["encryptionsalt"].as_str().is_some() => "passphrase",
+        None => "service",
+    };
+    Some(provider.to_string())
+}
+
+/// Get the type of backend the stack state is stored in, e.g. `cloud`, `s3` or `file`.
+///
+/// Like `pulumi` itself, we prefer `PULUMI_BACKEND_URL`, then the backend of the project and
+/// finally the backend logged in to.
+fn get_pulumi_backend(context: &Context, project: Option<&Yaml>) -> Option<String> {
+    let url = context
+        .get_env("PULUMI_BACKEND_URL")
+        .or_else(|| project?["backend"]["url"].as_str().map(ToString::to_string))
+        .or_else(|| get_pulumi_credentials(context)?.current)?;
+
+    let backend = match url_scheme(&url)? {
+        "http" | "https" => "cloud",
+        scheme => scheme,
+    };
+    Some(backend.to_string())
+}
+
+fn url_scheme(url: &str) -> Option<&str> {
+    url.split_once("://").map(|(scheme, _)| scheme)
+}
+
+fn get_pulumi_credentials(context: &Context) -> Option<Credentials> {
     let home_dir = pulumi_home_dir(context)?;
     let creds_path = home_dir.join("credentials.json");
 
     let file = File::open(creds_path).ok()?;
     let reader = BufReader::new(file);
 
-    // Read the JSON contents of the file as an instance of `User`.
-    let creds: Credentials = serde_json::from_reader(reader).ok()?;
+    // Read the JSON contents of the file as an instance of `Credentials`.
+    serde_json::from_reader(reader).ok()
+}
+
+fn get_pulumi_username(context: &Context) -> Option<String> {
+    let creds = get_pulumi_credentials(context)?;
 
     let current_api_provider = creds.current?;
 
@@ -494,4 +569,125 @@ mod tests {
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    #[test]
+    fn render_backend_and_stack_config() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dunce::canonicalize(dir.path())?;
+        std::fs::write(
+            root.join("Pulumi.yaml"),
+            "name: starship\nruntime: nodejs\nbackend:\n  url: s3://acme-pulumi-state\nstackConfigDir: config\n",
+        )?;
+
+        let workspace_path = root.join(".pulumi").join("workspaces");
+        std::fs::create_dir_all(&workspace_path)?;
+        std::fs::write(
+            workspace_path.join("starship-test-workspace.json"),
+            r#"{"stack": "acme/starship/prod"}"#,
+        )?;
+
+        let render = || {
+            ModuleRenderer::new("pulumi")
+                .env("HOME", root.to_str().unwrap())
+                .path(&root)
+                .logical_path(&root)
+                .config(toml::toml! {
+                    [pulumi]
+                    format = "[$stack( $backend)( $secrets_provider)( $missing_config)]($style)"
+                })
+                .collect()
+        };
+        assert_eq!(
+            render(),
+            Some(
+                Color::Fixed(5)
+                    .bold()
+                    .paint("acme/starship/prod s3 ⚠")
+                    .to_string()
+            )
+        );
+
+        std::fs::create_dir(root.join("config"))?;
+        std::fs::write(
+            root.join("config").join("Pulumi.prod.yaml"),
+            "secretsprovider: awskms://alias/pulumi?region=eu-west-1\nconfig:\n  aws:region: eu-west-1\n",
+        )?;
+        assert_eq!(
+            render(),
+            Some(
+                Color::Fixed(5)
+                    .bold()
+                    .paint("acme/starship/prod s3 awskms")
+                    .to_string()
+            )
+        );
+        dir.close()
+    }
+
+    #[test]
+    fn stack_classified_as_tier() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dunce::canonicalize(dir.path())?;
+        std::fs::write(
+            root.join("Pulumi.yaml"),
+            "name: starship\nruntime: nodejs\n",
+        )?;
+
+        let workspace_path = root.join(".pulumi").join("workspaces");
+        std::fs::create_dir_all(&workspace_path)?;
+        std::fs::write(
+            workspace_path.join("starship-test-workspace.json"),
+            r#"{"stack": "acme/starship/prod"}"#,
+        )?;
+
+        let actual = ModuleRenderer::new("pulumi")
+            .env("HOME", root.to_str().unwrap())
+            .path(&root)
+            .logical_path(&root)
+            .config(toml::toml! {
+                [environment_tiers.prod]
+                patterns = [".*/prod"]
+                style = "bold red"
+                [pulumi]
+                format = "via [$symbol$stack \\($tier\\)]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Red.bold().paint(" acme/starship/prod (prod)")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn secrets_provider_without_provider_url() {
+        let passphrase = YamlLoader::load_from_str("encryptionsalt: v1:abc\nconfig: {}\n").unwrap();
+        assert_eq!(
+            get_secrets_provider(&passphrase[0]),
+            Some("passphrase".to_string())
+        );
+
+        let service = YamlLoader::load_from_str("config: {}\n").unwrap();
+        assert_eq!(
+            get_secrets_provider(&service[0]),
+            Some("service".to_string())
+        );
+    }
+
+    #[test]
+    fn backend_from_env_and_credentials() {
+        let mut context = Context::new(Properties::default(), Target::Main);
+        context
+            .env
+            .insert("PULUMI_BACKEND_URL", "file://~".to_string());
+        assert_eq!(get_pulumi_backend(&context, None), Some("file".to_string()));
+        context
+            .env
+            .insert("PULUMI_BACKEND_URL", "https://api.pulumi.com".to_string());
+        assert_eq!(
+            get_pulumi_backend(&context, None),
+            Some("cloud".to_string())
+        );
+    }
 }
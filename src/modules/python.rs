@@ -1,12 +1,24 @@
 use ini::Ini;
 use std::path::Path;
 
+use super::utils::cache::CacheInputs;
 use super::{Context, Module, ModuleConfig};
 use crate::configs::python::PythonConfig;
 use crate::formatter::StringFormatter;
 use crate::formatter::VersionFormatter;
 use crate::utils::get_command_string_output;
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[
+        ".python-version",
+        "pyvenv.cfg",
+        "$PYENV_ROOT/version",
+        "~/.pyenv/version",
+    ],
+    env_vars: &["VIRTUAL_ENV", "PYENV_VERSION", "PYENV_ROOT"],
+};
+
 /// Creates a module with the current Python version and, if active, virtual environment.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("python");
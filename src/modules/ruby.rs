@@ -2,9 +2,16 @@ use regex::Regex;
 
 use super::{Context, Module, ModuleConfig};
 
+use super::utils::cache::CacheInputs;
 use crate::configs::ruby::RubyConfig;
 use crate::formatter::{StringFormatter, VersionFormatter};
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[".ruby-version", "Gemfile"],
+    env_vars: &["RUBY_VERSION", "RBENV_VERSION", "GEM_HOME"],
+};
+
 /// Creates a module with the current Ruby version
 ///
 /// Will display the Ruby version if any of the following criteria are met:
@@ -5,6 +5,7 @@ use std::process::Output;
 use serde::Deserialize;
 use std::collections::HashMap;
 
+use super::utils::cache::CacheInputs;
 use super::{Context, Module, ModuleConfig};
 
 use crate::configs::rust::RustConfig;
@@ -155,6 +156,17 @@ impl RustToolingEnvironmentInfo {
     }
 }
 
+/// The files and environment variables the version shown by this module depends on
+pub const CACHE_INPUTS: CacheInputs = CacheInputs {
+    files: &[
+        "rust-toolchain",
+        "rust-toolchain.toml",
+        "~/.rustup/settings.toml",
+        "$RUSTUP_HOME/settings.toml",
+    ],
+    env_vars: &["RUSTUP_TOOLCHAIN", "RUSTUP_HOME"],
+};
+
 /// Creates a module with the current Rust version
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("rust");
@@ -0,0 +1,342 @@
+use super::{Context, Module, ModuleConfig};
+
+use crate::configs::session::SessionConfig;
+use crate::formatter::StringFormatter;
+
+/// How many ancestors of the shell are inspected when looking for a remote server process
+#[cfg(target_os = "linux")]
+const MAX_ANCESTORS: usize = 16;
+
+struct Multiplexer {
+    name: &'static str,
+    session: Option<String>,
+    window: Option<String>,
+}
+
+/// Creates a module showing the remote session and terminal multiplexer the shell runs in
+///
+/// Will display the session if all of the following criteria are met:
+///     - `session.disabled` is false
+///     - the shell runs in a remote session (SSH, mosh, Eternal Terminal, VS Code remote,
+///       JetBrains Gateway or Coder) or inside tmux, screen or zellij
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("session");
+    let config = SessionConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let remote = remote_kind(context);
+    let multiplexer = if config.detect_multiplexers {
+        multiplexer(context)
+    } else {
+        None
+    };
+
+    let kind = remote.or(multiplexer.as_ref().map(|m| m.name))?;
+    let client_ip = client_ip(context);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.get_style(kind))),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "remote" => remote.map(Ok),
+                "multiplexer" => multiplexer.as_ref().map(|m| Ok(m.name)),
+                "session" => multiplexer.as_ref()?.session.as_deref().map(Ok),
+                "window" => multiplexer.as_ref()?.window.as_deref().map(Ok),
+                "client_ip" => client_ip.as_deref().map(Ok),
+                _ => None,
+            })
+            .parse(None, Some(context))
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `session`:\n{error}");
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn remote_kind(context: &Context) -> Option<&'static str> {
+    let has_env = |name: &str| context.get_env(name).is_some();
+    let env_is = |name: &str, value: &str| context.get_env(name).as_deref() == Some(value);
+    let ssh = has_env("SSH_CONNECTION") || has_env("SSH_CLIENT") || has_env("SSH_TTY");
+
+    if env_is("CODER", "true") || has_env("CODER_WORKSPACE_NAME") {
+        return Some("coder");
+    }
+
+    if env_is("TERM_PROGRAM", "vscode")
+        && (ssh || has_env("REMOTE_CONTAINERS") || has_env("CODESPACES"))
+    {
+        return Some("vscode");
+    }
+
+    if has_env("JETBRAINS_REMOTE_RUN") || (env_is("TERMINAL_EMULATOR", "JetBrains-JediTerm") && ssh)
+    {
+        return Some("jetbrains");
+    }
+
+    // mosh and Eternal Terminal don't announce themselves in the environment,
+    // so look for their server process among the ancestors of the shell.
+    #[cfg(target_os = "linux")]
+    if let Some(kind) = remote_ancestor(context) {
+        return Some(kind);
+    }
+
+    ssh.then_some("ssh")
+}
+
+#[cfg(target_os = "linux")]
+fn remote_ancestor(context: &Context) -> Option<&'static str> {
+    use crate::utils::{context_path, read_file};
+
+    let mut pid = std::os::unix::process::parent_id();
+    for _ in 0..MAX_ANCESTORS {
+        if pid <= 1 {
+            break;
+        }
+
+        // The format is `pid (comm) state ppid ...`, where `comm` may contain spaces
+        let stat = read_file(context_path(context, &format!("/proc/{pid}/stat"))).ok()?;
+        let (comm, rest) = stat.split_once(" (")?.1.rsplit_once(") ")?;
+        match comm {
+            "mosh-server" => return Some("mosh"),
+            "etserver" | "etterminal" => return Some("et"),
+            _ => (),
+        }
+
+        pid = rest.split_whitespace().nth(1)?.parse().ok()?;
+    }
+
+    None
+}
+
+fn multiplexer(context: &Context) -> Option<Multiplexer> {
+    if context.get_env("TMUX").is_some() {
+        // Session names can't contain a colon, window names can
+        let pane = context.get_env("TMUX_PANE");
+        let mut args = vec!["display-message", "-p"];
+        if let Some(pane) = pane.as_deref() {
+            args.extend(["-t", pane]);
+        }
+        args.push("#S:#W");
+
+        let output = context.exec_cmd("tmux", &args);
+        let (session, window) = output
+            .as_ref()
+            .and_then(|output| output.stdout.trim_end().split_once(':'))
+            .map_or((None, None), |(session, window)| {
+                (Some(session.to_string()), Some(window.to_string()))
+            });
+
+        return Some(Multiplexer {
+            name: "tmux",
+            session,
+            window,
+        });
+    }
+
+    if let Some(sty) = context.get_env("STY") {
+        // `STY` is `<pid>.<session name>`
+        return Some(Multiplexer {
+            name: "screen",
+            session: sty.split_once('.').map(|(_, name)| name.to_string()),
+            window: context.get_env("WINDOW"),
+        });
+    }
+
+    if context.get_env("ZELLIJ").is_some() {
+        return Some(Multiplexer {
+            name: "zellij",
+            session: context.get_env("ZELLIJ_SESSION_NAME"),
+            window: None,
+        });
+    }
+
+    None
+}
+
+fn client_ip(context: &Context) -> Option<String> {
+    context
+        .get_env("SSH_CONNECTION")
+        .or_else(|| context.get_env("SSH_CLIENT"))?
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
+    use nu_ansi_term::Color;
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("session")
+            .env("SSH_CONNECTION", "192.168.1.20 51234 192.168.1.10 22")
+            .collect();
+
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn no_session() {
+        let actual = ModuleRenderer::new("session")
+            .config(toml::toml! {
+                [session]
+                disabled = false
+            })
+            .collect();
+
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn ssh_with_client_ip() {
+        let actual = ModuleRenderer::new("session")
+            .config(toml::toml! {
+                [session]
+                disabled = false
+                format = "[$remote from $client_ip]($style)"
+            })
+            .env("SSH_CONNECTION", "192.168.1.20 51234 192.168.1.10 22")
+            .collect();
+
+        let expected = Some(format!(
+            "{}",
+            Color::Yellow.bold().paint("ssh from 192.168.1.20")
+        ));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn remote_styles() {
+        let actual = ModuleRenderer::new("session")
+            .config(toml::toml! {
+                [session]
+                disabled = false
+                [session.styles]
+                vscode = "bold red"
+            })
+            .env("TERM_PROGRAM", "vscode")
+            .env("SSH_CONNECTION", "10.0.0.2 40000 10.0.0.1 22")
+            .collect();
+
+        let expected = Some(format!("{} ", Color::Red.bold().paint("⇄ vscode")));
+        assert_eq!(actual, expected);
+
+        let actual = ModuleRenderer::new("session")
+            .config(toml::toml! {
+                [session]
+                disabled = false
+            })
+            .env("CODER", "true")
+            .env("CODER_WORKSPACE_NAME", "dev")
+            .collect();
+
+        let expected = Some(format!("{} ", Color::LightBlue.bold().paint("⇄ coder")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn tmux_session_and_window() {
+        let actual = ModuleRenderer::new("session")
+            .config(toml::toml! {
+                [session]
+                disabled = false
+                format = "[$symbol( $remote)( $multiplexer:$session/$window)]($style) "
+            })
+            .env("TMUX", "/tmp/tmux-1000/default,1234,0")
+            .env("TMUX_PANE", "%3")
+            .env("SSH_CONNECTION", "192.168.1.20 51234 192.168.1.10 22")
+            .cmd(
+                "tmux display-message -p -t %3 #S:#W",
+                Some(CommandOutput {
+                    stdout: "work:vim: notes\n".to_string(),
+                    stderr: String::new(),
+                }),
+            )
+            .collect();
+
+        let expected = Some(format!(
+            "{} ",
+            Color::Yellow.bold().paint("⇄ ssh tmux:work/vim: notes")
+        ));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn screen_and_zellij_sessions() {
+        let config = toml::toml! {
+            [session]
+            disabled = false
+            format = "$multiplexer:$session( $window)"
+        };
+
+        let actual = ModuleRenderer::new("session")
+            .config(config.clone())
+            .env("STY", "4242.pts-1.build")
+            .env("WINDOW", "2")
+            .collect();
+        assert_eq!(actual.as_deref(), Some("screen:pts-1.build 2"));
+
+        let actual = ModuleRenderer::new("session")
+            .config(config.clone())
+            .env("ZELLIJ", "0")
+            .env("ZELLIJ_SESSION_NAME", "quiet-otter")
+            .collect();
+        assert_eq!(actual.as_deref(), Some("zellij:quiet-otter"));
+
+        let actual = ModuleRenderer::new("session")
+            .config(toml::toml! {
+                [session]
+                disabled = false
+                detect_multiplexers = false
+            })
+            .env("ZELLIJ", "0")
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn mosh_from_process_ancestry() -> std::io::Result<()> {
+        let renderer = ModuleRenderer::new("session").config(toml::toml! {
+            [session]
+            disabled = false
+            format = "[$remote]($style)"
+        });
+
+        let parent = std::os::unix::process::parent_id();
+        let proc_dir = renderer.root_path().join("proc");
+        std::fs::create_dir_all(proc_dir.join(parent.to_string()))?;
+        std::fs::create_dir_all(proc_dir.join("4242"))?;
+        std::fs::write(
+            proc_dir.join(parent.to_string()).join("stat"),
+            format!("{parent} (bash) S 4242 4242 4242 0 -1"),
+        )?;
+        std::fs::write(
+            proc_dir.join("4242/stat"),
+            "4242 (mosh-server) S 1 4242 4242 0 -1",
+        )?;
+
+        let actual = renderer.collect();
+        let expected = Some(format!("{}", Color::Green.bold().paint("mosh")));
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -47,11 +47,11 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         PipeStatusStatus::Disabled
     };
 
-    // Exit code is zero while success_symbol and pipestatus are all zero or disabled/missing
-    if exit_code == "0"
+    // Exit code is a success while success_symbol and pipestatus are all successes or disabled/missing
+    if is_success_code(exit_code, &config)
         && config.success_symbol.is_empty()
         && (match pipestatus_status {
-            PipeStatusStatus::Pipe(ps) => ps.iter().all(|s| s == "0"),
+            PipeStatusStatus::Pipe(ps) => ps.iter().all(|s| is_success_code(s, &config)),
             _ => true,
         })
     {
@@ -107,6 +107,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// Whether the exit code is `0` or one of the configured `success_codes`
+fn is_success_code(exit_code: &str, config: &StatusConfig) -> bool {
+    exit_code
+        .parse::<i64>()
+        .is_ok_and(|i| i == 0 || config.success_codes.contains(&(i as ExitCode)))
+}
+
 fn format_exit_code<'a>(
     exit_code: &'a str,
     format: &'a str,
@@ -123,10 +130,13 @@ fn format_exit_code<'a>(
     };
 
     let hex_status = format!("0x{exit_code_int:X}");
+    let is_success = exit_code_int == 0 || config.success_codes.contains(&exit_code_int);
 
-    let common_meaning = status_common_meaning(exit_code_int);
+    // A meaning configured by the user takes precedence over the signal interpretation
+    let user_meaning = config.meanings.get(&exit_code_int.to_string()).copied();
+    let common_meaning = user_meaning.or_else(|| status_common_meaning(exit_code_int));
 
-    let raw_signal_number = if config.recognize_signal_code {
+    let raw_signal_number = if config.recognize_signal_code && user_meaning.is_none() {
         status_to_signal(exit_code_int)
     } else {
         None
@@ -146,7 +156,7 @@ fn format_exit_code<'a>(
         formatter
             .map_meta(|var, _| match var {
                 "symbol" => match exit_code_int {
-                    0 => Some(config.success_symbol),
+                    _ if is_success => Some(config.success_symbol),
                     126 if config.map_symbol => Some(config.not_executable_symbol),
                     127 if config.map_symbol => Some(config.not_found_symbol),
                     130 if config.recognize_signal_code && config.map_symbol => {
@@ -163,7 +173,7 @@ fn format_exit_code<'a>(
                 _ => None,
             })
             .map_style(|variable| match variable {
-                "style" => Some(Ok(if exit_code_int == 0 {
+                "style" => Some(Ok(if is_success {
                     config.success_style
                 } else {
                     config.failure_style
@@ -206,7 +216,7 @@ fn status_common_meaning(ex: ExitCode) -> Option<&'static str> {
         1 => Some("ERROR"),
         2 => Some("USAGE"),
 
-        // status codes 64-78 from libc
+        // status codes 64-78 from sysexits.h
         64 => Some("USAGE"),
         65 => Some("DATAERR"),
         66 => Some("NOINPUT"),
@@ -223,8 +233,11 @@ fn status_common_meaning(ex: ExitCode) -> Option<&'static str> {
         77 => Some("NOPERM"),
         78 => Some("CONFIG"),
 
+        // conventions of coreutils' timeout, shells and git
+        124 => Some("TIMEOUT"),
         126 => Some("NOPERM"),
         127 => Some("NOTFOUND"),
+        128 => Some("FATAL"),
         _ => None,
     }
 }
@@ -444,8 +457,8 @@ mod tests {
     #[test]
     fn exit_code_name_no_signal() {
         let exit_values = [
-            1, 2, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 126, 127, 130, 101,
-            132,
+            1, 2, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 124, 126, 127, 128,
+            130, 101, 132,
         ];
         let exit_values_name = [
             Some("ERROR"),
@@ -465,8 +478,10 @@ mod tests {
             Some("PROTOCOL"),
             Some("NOPERM"),
             Some("CONFIG"),
+            Some("TIMEOUT"),
             Some("NOPERM"),
             Some("NOTFOUND"),
+            Some("FATAL"),
             None,
             None,
             None,
@@ -487,6 +502,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn custom_meanings() {
+        let exit_values = [1, 3, 101, 255];
+        let exit_values_name = [Some("NOMATCH"), Some("3"), Some("PANIC"), Some("SSH")];
+
+        for (status, name) in exit_values.iter().zip(&exit_values_name) {
+            let expected = name.map(std::string::ToString::to_string);
+            let actual = ModuleRenderer::new("status")
+                .config(toml::toml! {
+                    [status]
+                    format = "$common_meaning$signal_name$maybe_int"
+                    meanings = { "1" = "NOMATCH", "101" = "PANIC", "255" = "SSH" }
+                    disabled = false
+                })
+                .status(*status)
+                .collect();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn success_codes() {
+        let exit_values = [0, 1, 2, 141];
+        let exit_values_rendered = [None, None, Some("🔴2"), Some("🔴PIPE")];
+
+        for (status, rendered) in exit_values.iter().zip(&exit_values_rendered) {
+            let expected = rendered.map(std::string::ToString::to_string);
+            let actual = ModuleRenderer::new("status")
+                .config(toml::toml! {
+                    [status]
+                    format = "$symbol$common_meaning$signal_name"
+                    symbol = "🔴"
+                    meanings = { "2" = "2" }
+                    success_codes = [1]
+                    disabled = false
+                })
+                .status(*status)
+                .collect();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn success_codes_use_success_symbol_and_style() {
+        let expected = Some(format!("{} ", Color::Green.paint("🟢1")));
+        let actual = ModuleRenderer::new("status")
+            .config(toml::toml! {
+                [status]
+                symbol = "🔴"
+                success_symbol = "🟢"
+                success_style = "green"
+                failure_style = "red"
+                success_codes = [1]
+                disabled = false
+            })
+            .status(1)
+            .collect();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn maybe_exit_code_number() {
         let exit_values = [1, 2, 126, 127, 130, 101, 6, -3];
@@ -768,6 +843,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pipeline_meanings_and_success_codes() {
+        let exit_values = [[1, 0, 141, 1], [1, 1, 141, 1], [2, 141, 2, 1]];
+        let exit_values_rendered = [None, None, Some("NOMATCH=PIPE 🔴NOMATCH ERROR")];
+
+        for (status, rendered) in exit_values.iter().zip(&exit_values_rendered) {
+            let main_exit_code = status[0];
+            let pipe_exit_code = &status[1..];
+
+            let expected = rendered.map(std::string::ToString::to_string);
+            let actual = ModuleRenderer::new("status")
+                .config(toml::toml! {
+                    [status]
+                    format = "$symbol$common_meaning$signal_name"
+                    symbol = "🔴"
+                    meanings = { "2" = "NOMATCH" }
+                    success_codes = [1, 141]
+                    pipestatus = true
+                    pipestatus_separator = " "
+                    pipestatus_format = "$common_meaning=$pipestatus"
+                    disabled = false
+                })
+                .status(main_exit_code)
+                .pipestatus(pipe_exit_code)
+                .collect();
+            assert_eq!(expected, actual);
+        }
+    }
+
     #[test]
     fn pipestatus_segment_format() {
         let pipe_exit_code = &[0, 1];
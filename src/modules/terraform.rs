@@ -6,7 +6,8 @@ use crate::utils;
 
 use crate::formatter::VersionFormatter;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
 /// Creates a module with the current Terraform version and workspace
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
@@ -24,6 +25,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
+    let datadir = get_terraform_datadir(context);
+    let backend = LazyLock::new(|| get_terraform_backend(&datadir));
+
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|variable, _| match variable {
@@ -38,7 +42,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 "version" => {
                     let terraform_version = parse_terraform_version(
                         context
-                            .exec_cmds_return_first(config.commands.clone())?
+                            .exec_cmds_return_first(&config.commands)?
                             .stdout
                             .as_str(),
                     )?;
@@ -49,7 +53,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     )
                 }
                 .map(Ok),
-                "workspace" => get_terraform_workspace(context).map(Ok),
+                "workspace" => get_terraform_workspace(context, &datadir).map(Ok),
+                "plan" => {
+                    has_saved_plan(context, &config).then(|| Ok(config.plan_symbol.to_string()))
+                }
+                "init" => needs_init(context, &datadir).then(|| Ok(config.init_symbol.to_string())),
+                "backend" => backend.as_ref().map(|backend| Ok(backend.kind.clone())),
+                "backend_location" => backend.as_ref()?.location.clone().map(Ok),
                 _ => None,
             })
             .parse(None, Some(context))
@@ -66,19 +76,22 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+// Data directory containing current workspace, backend and providers can be overwritten by an env var
+fn get_terraform_datadir(context: &Context) -> PathBuf {
+    match context.get_env("TF_DATA_DIR") {
+        Some(s) => PathBuf::from(s),
+        None => context.current_dir.join(".terraform"),
+    }
+}
+
 // Determines the currently selected workspace (see https://github.com/hashicorp/terraform/blob/master/command/meta.go for the original implementation)
-fn get_terraform_workspace(context: &Context) -> Option<String> {
+fn get_terraform_workspace(context: &Context, datadir: &Path) -> Option<String> {
     // Workspace can be explicitly overwritten by an env var
     let workspace_override = context.get_env("TF_WORKSPACE");
     if workspace_override.is_some() {
         return workspace_override;
     }
 
-    // Data directory containing current workspace can be overwritten by an env var
-    let datadir = match context.get_env("TF_DATA_DIR") {
-        Some(s) => PathBuf::from(s),
-        None => context.current_dir.join(".terraform"),
-    };
     match utils::read_file(datadir.join("environment")) {
         Err(ref e) if e.kind() == io::ErrorKind::NotFound => Some("default".to_string()),
         Ok(s) => Some(s),
@@ -86,6 +99,103 @@ fn get_terraform_workspace(context: &Context) -> Option<String> {
     }
 }
 
+struct TerraformBackend {
+    kind: String,
+    location: Option<String>,
+}
+
+// `terraform init` records the configured backend in `terraform.tfstate` of the data directory
+fn get_terraform_backend(datadir: &Path) -> Option<TerraformBackend> {
+    let state = utils::read_file(datadir.join("terraform.tfstate")).ok()?;
+    let state: serde_json::Value = serde_json::from_str(&state).ok()?;
+    let backend = state.get("backend")?;
+    let kind = backend.get("type")?.as_str()?;
+
+    let config = backend.get("config");
+    let field = |name: &str| {
+        config?
+            .get(name)?
+            .as_str()
+            .filter(|value| !value.is_empty())
+    };
+    let join = |fields: &[Option<&str>]| {
+        let fields = fields.iter().flatten().copied().collect::<Vec<_>>();
+        (!fields.is_empty()).then(|| fields.join("/"))
+    };
+
+    let location = match kind {
+        "s3" | "oss" | "cos" => join(&[field("bucket"), field("key")]),
+        "gcs" => join(&[field("bucket"), field("prefix")]),
+        "azurerm" => join(&[
+            field("storage_account_name"),
+            field("container_name"),
+            field("key"),
+        ]),
+        "remote" | "cloud" => {
+            let workspace = config
+                .and_then(|config| config.get("workspaces")?.get("name")?.as_str())
+                .filter(|name| !name.is_empty());
+            join(&[field("organization"), workspace])
+        }
+        "local" | "consul" => field("path").map(String::from),
+        "http" => field("address").map(String::from),
+        _ => None,
+    };
+
+    Some(TerraformBackend {
+        kind: kind.to_string(),
+        location,
+    })
+}
+
+fn has_saved_plan(context: &Context, config: &TerraformConfig) -> bool {
+    context.try_begin_scan().is_some_and(|scan| {
+        scan.set_files(&config.plan_files)
+            .set_extensions(&config.plan_extensions)
+            .is_match()
+    })
+}
+
+// The lock file pins a version for each provider, which `terraform init` installs into
+// `providers/<address>/<version>` of the data directory. A pinned version that is not installed
+// means the lock file changed since the last `init`.
+fn needs_init(context: &Context, datadir: &Path) -> bool {
+    let Ok(lock_file) = utils::read_file(context.current_dir.join(".terraform.lock.hcl")) else {
+        return false;
+    };
+
+    parse_locked_providers(&lock_file)
+        .iter()
+        .any(|(address, version)| {
+            !datadir
+                .join("providers")
+                .join(address)
+                .join(version)
+                .is_dir()
+        })
+}
+
+fn parse_locked_providers(lock_file: &str) -> Vec<(&str, &str)> {
+    let mut providers = Vec::new();
+    let mut address = None;
+
+    for line in lock_file.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("provider ") {
+            address = rest.split('"').nth(1);
+        } else if let Some(value) = line.strip_prefix("version") {
+            let version = value
+                .trim_start()
+                .strip_prefix('=')
+                .map(|v| v.trim().trim_matches('"'));
+            if let (Some(provider), Some(version)) = (address.take(), version) {
+                providers.push((provider, version));
+            }
+        }
+    }
+
+    providers
+}
+
 fn parse_terraform_version(version: &str) -> Option<String> {
     // `terraform version` or `tofu version` output looks like this
     //   Terraform v0.12.14/OpenTofu v1.7.2
@@ -319,4 +429,125 @@ is 0.12.14. You can update by downloading from www.terraform.io/downloads.html
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    #[test]
+    fn folder_with_backend() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let tf_dir = dir.path().join(".terraform");
+        fs::create_dir(&tf_dir)?;
+        fs::write(
+            tf_dir.join("terraform.tfstate"),
+            r#"{
+                "version": 3,
+                "backend": {
+                    "type": "s3",
+                    "config": { "bucket": "acme-state", "key": "network/terraform.tfstate", "region": "eu-west-1" },
+                    "hash": 1234
+                }
+            }"#,
+        )?;
+
+        let actual = ModuleRenderer::new("terraform")
+            .path(dir.path())
+            .config(toml::toml! {
+                [terraform]
+                format = "via [$symbol$workspace( $backend:$backend_location)]($style) "
+            })
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105)
+                .bold()
+                .paint("💠 default s3:acme-state/network/terraform.tfstate")
+        ));
+
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_cloud_backend() -> io::Result<()> {
+        let datadir = tempfile::tempdir()?;
+        fs::write(
+            datadir.path().join("terraform.tfstate"),
+            r#"{"backend": {"type": "cloud", "config": {"organization": "acme", "workspaces": {"name": "network", "tags": null}}}}"#,
+        )?;
+
+        assert_eq!(
+            get_terraform_backend(datadir.path()).and_then(|backend| backend.location),
+            Some("acme/network".to_string())
+        );
+        datadir.close()
+    }
+
+    #[test]
+    fn folder_with_saved_plan() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("main.tf"))?;
+
+        let renderer = || {
+            ModuleRenderer::new("terraform")
+                .path(dir.path())
+                .config(toml::toml! {
+                    [terraform]
+                    format = "[$workspace( $plan)]($style)"
+                })
+                .collect()
+        };
+        assert_eq!(
+            renderer(),
+            Some(Color::Fixed(105).bold().paint("default").to_string())
+        );
+
+        File::create(dir.path().join("plan.out"))?;
+        assert_eq!(
+            renderer(),
+            Some(Color::Fixed(105).bold().paint("default 📋").to_string())
+        );
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_providers_not_installed() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("main.tf"))?;
+        fs::write(
+            dir.path().join(".terraform.lock.hcl"),
+            r#"# This file is maintained automatically by "terraform init".
+provider "registry.terraform.io/hashicorp/aws" {
+  version     = "5.31.0"
+  constraints = "~> 5.0"
+  hashes = [
+    "h1:ltxyuBWIy9cq0kIKDJH1jeWJy/y7XJLjS4QrsQK4plA=",
+  ]
+}
+"#,
+        )?;
+        let provider_dir = dir
+            .path()
+            .join(".terraform/providers/registry.terraform.io/hashicorp/aws");
+
+        let renderer = || {
+            ModuleRenderer::new("terraform")
+                .path(dir.path())
+                .config(toml::toml! {
+                    [terraform]
+                    format = "[$workspace( $init)]($style)"
+                })
+                .collect()
+        };
+
+        fs::create_dir_all(provider_dir.join("5.30.0/linux_amd64"))?;
+        assert_eq!(
+            renderer(),
+            Some(Color::Fixed(105).bold().paint("default ⚠").to_string())
+        );
+
+        fs::create_dir_all(provider_dir.join("5.31.0/linux_amd64"))?;
+        assert_eq!(
+            renderer(),
+            Some(Color::Fixed(105).bold().paint("default").to_string())
+        );
+        dir.close()
+    }
 }
@@ -0,0 +1,323 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::context::Context;
+use crate::module::Module;
+use crate::segment::Segment;
+use crate::utils;
+
+/// Number of directories whose output is remembered for each module
+const CACHE_SIZE: usize = 256;
+
+/// The inputs the output of a module is derived from, besides its configuration
+/// and the entries of the current directory
+pub struct CacheInputs {
+    /// File names looked up in the current directory and its parents, or paths relative
+    /// to the home directory when starting with `~/`, or to an environment variable's
+    /// directory when starting with `$VAR/`
+    pub files: &'static [&'static str],
+    /// Environment variables, in addition to `PATH`
+    pub env_vars: &'static [&'static str],
+}
+
+/// The state of the inputs of a module when its output was computed
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Fingerprint {
+    version: String,
+    config: Option<String>,
+    config_modified: Option<SystemTime>,
+    dir_modified: Option<SystemTime>,
+    files: Vec<(PathBuf, SystemTime)>,
+    env_vars: Vec<Option<String>>,
+}
+
+impl Fingerprint {
+    fn new(module: &str, inputs: &CacheInputs, context: &Context) -> Self {
+        let modified = |path: &Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+
+        let files = inputs
+            .files
+            .iter()
+            .flat_map(|file| {
+                if let Some(file) = file.strip_prefix("~/") {
+                    context
+                        .get_home()
+                        .map(|home| home.join(file))
+                        .into_iter()
+                        .collect()
+                } else if let Some((var, file)) =
+                    file.strip_prefix('$').and_then(|file| file.split_once('/'))
+                {
+                    context
+                        .get_env_os(var)
+                        .map(|dir| Path::new(&dir).join(file))
+                        .into_iter()
+                        .collect()
+                } else {
+                    context
+                        .current_dir
+                        .ancestors()
+                        .map(|dir| dir.join(file))
+                        .collect::<Vec<_>>()
+                }
+            })
+            .filter_map(|path| modified(&path).map(|time| (path, time)))
+            .collect();
+
+        let env_vars = ["PATH"]
+            .iter()
+            .chain(inputs.env_vars)
+            .map(|name| context.get_env(name))
+            .collect();
+
+        Self {
+            version: crate::shadow::PKG_VERSION.to_string(),
+            config: context
+                .config
+                .get_module_config(module)
+                .map(toml::Value::to_string),
+            config_modified: context
+                .get_config_path_os()
+                .and_then(|path| modified(Path::new(&path))),
+            dir_modified: modified(&context.current_dir),
+            files,
+            env_vars,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct CacheEntry {
+    fingerprint: Fingerprint,
+    created: SystemTime,
+    segments: Vec<Segment>,
+}
+
+/// The output of a module in the directories it was last computed in, persisted between prompts
+struct ModuleCache {
+    path: PathBuf,
+    entries: IndexMap<PathBuf, CacheEntry>,
+    changed: bool,
+}
+
+impl ModuleCache {
+    fn load(path: PathBuf) -> Self {
+        let entries = fs::read(&path)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_slice(&content)
+                    .map_err(|e| log::debug!("Ignoring invalid module cache {path:?}: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        Self {
+            path,
+            entries,
+            changed: false,
+        }
+    }
+
+    /// Returns the segments computed in `dir`, if none of the inputs have changed since
+    fn get(&self, dir: &Path, fingerprint: &Fingerprint, ttl: Duration) -> Option<&[Segment]> {
+        let entry = self.entries.get(dir)?;
+        let age = SystemTime::now().duration_since(entry.created).ok()?;
+        (age < ttl && entry.fingerprint == *fingerprint).then_some(entry.segments.as_slice())
+    }
+
+    fn insert(&mut self, dir: &Path, fingerprint: Fingerprint, segments: Vec<Segment>) {
+        self.entries.shift_remove(dir);
+        self.entries.insert(
+            dir.to_path_buf(),
+            CacheEntry {
+                fingerprint,
+                created: SystemTime::now(),
+                segments,
+            },
+        );
+        while self.entries.len() > CACHE_SIZE {
+            self.entries.shift_remove_index(0);
+        }
+        self.changed = true;
+    }
+
+    fn save(self) {
+        if !self.changed {
+            return;
+        }
+        let result = serde_json::to_vec(&self.entries)
+            .map_err(std::io::Error::from)
+            .and_then(|content| utils::replace_file(&self.path, &content));
+        if let Err(e) = result {
+            log::debug!("Unable to write module cache {:?}: {e}", self.path);
+        }
+    }
+}
+
+/// Reuses the output `compute` had in the current directory on a previous prompt,
+/// unless `module_cache_ttl` has passed or any of the `inputs` have changed since.
+pub fn memoize<'a>(
+    module: &str,
+    inputs: &CacheInputs,
+    context: &'a Context,
+    compute: impl FnOnce() -> Option<Module<'a>>,
+) -> Option<Module<'a>> {
+    let ttl = context.root_config.module_cache_ttl;
+    if ttl == 0 {
+        return compute();
+    }
+
+    let path = context
+        .get_cache_dir()
+        .join("modules")
+        .join(format!("{module}.json"));
+    let mut cache = ModuleCache::load(path);
+    let fingerprint = Fingerprint::new(module, inputs, context);
+
+    if let Some(segments) = cache.get(&context.current_dir, &fingerprint, Duration::from_secs(ttl))
+    {
+        log::debug!("Using the cached output of module {module:?}");
+        let mut cached = context.new_module(module);
+        cached.set_segments(segments.to_vec());
+        return Some(cached);
+    }
+
+    // Modules that aren't shown are cheap to compute again, and might have failed
+    let computed = compute()?;
+    cache.insert(&context.current_dir, fingerprint, computed.segments.clone());
+    cache.save();
+    Some(computed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
+    use nu_ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    fn render(dir: &std::path::Path, cache: &std::path::Path, version: &str) -> Option<String> {
+        ModuleRenderer::new("nodejs")
+            .path(dir)
+            .config(toml::toml! {
+                module_cache_ttl = 3600
+            })
+            .env("STARSHIP_CACHE", cache.to_string_lossy())
+            .cmd(
+                "node --version",
+                Some(CommandOutput {
+                    stdout: format!("{version}\n"),
+                    stderr: String::new(),
+                }),
+            )
+            .collect()
+    }
+
+    fn expected(version: &str) -> Option<String> {
+        Some(format!(
+            "via {}",
+            Color::Green.bold().paint(format!("\u{e718} {version} "))
+        ))
+    }
+
+    #[test]
+    fn reuses_output_until_inputs_change() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = tempfile::tempdir()?;
+        File::create(dir.path().join("package.json"))?.sync_all()?;
+
+        assert_eq!(
+            render(dir.path(), cache.path(), "v20.0.0"),
+            expected("v20.0.0")
+        );
+        assert!(cache.path().join("modules/nodejs.json").exists());
+
+        // `node` isn't run again while the inputs stay the same
+        assert_eq!(
+            render(dir.path(), cache.path(), "v22.0.0"),
+            expected("v20.0.0")
+        );
+
+        // Declared files are looked up in parent directories
+        let nested = dir.path().join("src");
+        fs::create_dir(&nested)?;
+        File::create(nested.join("index.js"))?.sync_all()?;
+        assert_eq!(
+            render(&nested, cache.path(), "v22.0.0"),
+            expected("v22.0.0")
+        );
+        File::create(dir.path().join(".nvmrc"))?.sync_all()?;
+        assert_eq!(
+            render(&nested, cache.path(), "v23.0.0"),
+            expected("v23.0.0")
+        );
+
+        dir.close()?;
+        cache.close()
+    }
+
+    #[test]
+    fn files_relative_to_env_var() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = tempfile::tempdir()?;
+        let pyenv_root = tempfile::tempdir()?;
+        File::create(dir.path().join("requirements.txt"))?.sync_all()?;
+
+        let render = |version: &str| {
+            ModuleRenderer::new("python")
+                .path(dir.path())
+                .config(toml::toml! {
+                    module_cache_ttl = 3600
+                })
+                .env("STARSHIP_CACHE", cache.path().to_string_lossy())
+                .env("PYENV_ROOT", pyenv_root.path().to_string_lossy())
+                .cmd(
+                    "python --version",
+                    Some(CommandOutput {
+                        stdout: String::new(),
+                        stderr: format!("Python {version}\n"),
+                    }),
+                )
+                .collect()
+        };
+        let expected = |version: &str| {
+            Some(format!(
+                "via {}",
+                Color::Yellow.bold().paint(format!("🐍 v{version} "))
+            ))
+        };
+
+        assert_eq!(render("3.8.0"), expected("3.8.0"));
+        assert_eq!(render("3.12.0"), expected("3.8.0"));
+
+        // Selecting a global version with `pyenv global` writes `$PYENV_ROOT/version`
+        File::create(pyenv_root.path().join("version"))?.sync_all()?;
+        assert_eq!(render("3.12.0"), expected("3.12.0"));
+
+        dir.close()?;
+        pyenv_root.close()?;
+        cache.close()
+    }
+
+    #[test]
+    fn disabled_without_ttl() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = tempfile::tempdir()?;
+        File::create(dir.path().join("package.json"))?.sync_all()?;
+
+        let actual = ModuleRenderer::new("nodejs")
+            .path(dir.path())
+            .env("STARSHIP_CACHE", cache.path().to_string_lossy())
+            .collect();
+
+        assert_eq!(actual, expected("v12.0.0"));
+        assert!(!cache.path().join("modules").exists());
+
+        dir.close()?;
+        cache.close()
+    }
+}
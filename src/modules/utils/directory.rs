@@ -1,3 +1,7 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::print::UnicodeWidthGraphemes;
+
 /// Truncate a path to only have a set number of path components
 ///
 /// Will truncate a path to only show the last `length` components in a path.
@@ -23,6 +27,75 @@ pub fn truncate(dir_string: &str, length: usize) -> Option<String> {
     Some(truncated_components.join("/"))
 }
 
+/// Shorten the middle components of a path until it fits into `max_width` columns
+///
+/// The first and the last component are kept intact for as long as possible.
+/// The path is progressively shortened in three stages, each only as far as
+/// needed:
+/// 1. middle components are abbreviated to `fish_length` characters, left to right,
+/// 2. runs of middle components are collapsed into `ellipsis`, left to right,
+/// 3. everything but the last component is collapsed into `ellipsis`.
+///
+/// A value will only be returned if the path has been shortened.
+pub fn truncate_to_width(
+    dir_string: &str,
+    max_width: usize,
+    fish_length: usize,
+    ellipsis: &str,
+) -> Option<String> {
+    if dir_string.width_graphemes() <= max_width {
+        return None;
+    }
+
+    let mut components = dir_string.split('/').map(String::from).collect::<Vec<_>>();
+    let last = components.len() - 1;
+    if last == 0 {
+        return None;
+    }
+
+    let fits = |components: &[String]| components.join("/").width_graphemes() <= max_width;
+
+    for i in 1..last {
+        components[i] = abbreviate_component(&components[i], fish_length);
+        if fits(&components) {
+            return Some(components.join("/"));
+        }
+    }
+
+    for run in 1..last {
+        let mut collapsed = vec![components[0].clone()];
+        if !ellipsis.is_empty() {
+            collapsed.push(ellipsis.to_string());
+        }
+        collapsed.extend_from_slice(&components[run + 1..]);
+        if fits(&collapsed) {
+            return Some(collapsed.join("/"));
+        }
+    }
+
+    let last = &components[last];
+    if ellipsis.is_empty() {
+        Some(last.clone())
+    } else {
+        Some(format!("{ellipsis}/{last}"))
+    }
+}
+
+/// Shorten a single path component to `length` graphemes, keeping a leading dot
+fn abbreviate_component(component: &str, length: usize) -> String {
+    let graphemes = component.graphemes(true).collect::<Vec<_>>();
+    let length = if component.starts_with('.') {
+        length + 1
+    } else {
+        length
+    };
+    if graphemes.len() <= length {
+        component.to_string()
+    } else {
+        graphemes[..length].concat()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +141,60 @@ mod tests {
         let output = truncate(path, 3);
         assert_eq!(output.as_deref(), Some("engines/booster/rocket"));
     }
+
+    #[test]
+    fn truncate_to_width_path_that_fits() {
+        let path = "~/starship/engines/booster";
+        let output = truncate_to_width(path, 30, 1, "…");
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn truncate_to_width_abbreviates_only_as_needed() {
+        let path = "~/starship/engines/booster/rocket";
+        let output = truncate_to_width(path, 28, 1, "…");
+        assert_eq!(output.as_deref(), Some("~/s/engines/booster/rocket"));
+    }
+
+    #[test]
+    fn truncate_to_width_abbreviates_all_middle_components() {
+        let path = "~/starship/engines/booster/rocket";
+        let output = truncate_to_width(path, 14, 1, "…");
+        assert_eq!(output.as_deref(), Some("~/s/e/b/rocket"));
+    }
+
+    #[test]
+    fn truncate_to_width_keeps_leading_dot() {
+        let path = "~/.config/starship/rocket";
+        let output = truncate_to_width(path, 15, 1, "…");
+        assert_eq!(output.as_deref(), Some("~/.c/s/rocket"));
+    }
+
+    #[test]
+    fn truncate_to_width_collapses_middle_components() {
+        let path = "~/starship/engines/booster/rocket";
+        let output = truncate_to_width(path, 12, 1, "…");
+        assert_eq!(output.as_deref(), Some("~/…/b/rocket"));
+    }
+
+    #[test]
+    fn truncate_to_width_drops_leading_components() {
+        let path = "/starship/engines/booster/rocket";
+        let output = truncate_to_width(path, 7, 1, "…");
+        assert_eq!(output.as_deref(), Some("…/rocket"));
+    }
+
+    #[test]
+    fn truncate_to_width_without_ellipsis() {
+        let path = "~/starship/engines/booster/rocket";
+        let output = truncate_to_width(path, 6, 1, "");
+        assert_eq!(output.as_deref(), Some("rocket"));
+    }
+
+    #[test]
+    fn truncate_to_width_single_component() {
+        let path = "rocket";
+        let output = truncate_to_width(path, 3, 1, "…");
+        assert_eq!(output, None);
+    }
 }
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+/// The address of a server without its scheme, e.g. `vault.example.com:8200`.
+///
+/// The scheme is optional, as e.g. `CONSUL_HTTP_ADDR` is often set without one.
+pub fn display_address(address: &str) -> &str {
+    address
+        .split_once("://")
+        .map_or(address, |(_, rest)| rest)
+        .trim_end_matches('/')
+}
+
+/// The alias configured for `value` in `aliases`, or `value` itself
+pub fn alias<'a>(aliases: &HashMap<String, &'a str>, value: &'a str) -> &'a str {
+    aliases.get(value).copied().unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_without_scheme() {
+        assert_eq!(
+            display_address("https://vault.example.com:8200/"),
+            "vault.example.com:8200"
+        );
+        assert_eq!(display_address("127.0.0.1:8500"), "127.0.0.1:8500");
+    }
+
+    #[test]
+    fn aliased_or_unchanged() {
+        let aliases = HashMap::from([("vault.example.com:8200".to_string(), "prod")]);
+        assert_eq!(alias(&aliases, "vault.example.com:8200"), "prod");
+        assert_eq!(alias(&aliases, "localhost:8200"), "localhost:8200");
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cache;
 pub mod directory;
 
 #[cfg(target_os = "windows")]
@@ -6,6 +7,10 @@ pub mod directory_win;
 #[cfg(not(target_os = "windows"))]
 pub mod directory_nix;
 
+pub mod hashicorp;
+
 pub mod path;
 
+pub mod tier;
+
 pub mod truncate;
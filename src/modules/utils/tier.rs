@@ -0,0 +1,133 @@
+use regex::Regex;
+
+use crate::configs::EnvironmentTierConfig;
+use crate::context::Context;
+
+/// The environment tier, e.g. `prod`, that the account, project or context shown by a cloud
+/// module belongs to, as classified by `environment_tiers`
+pub struct Tier<'a> {
+    pub name: &'a str,
+    config: &'a EnvironmentTierConfig,
+}
+
+impl<'a> Tier<'a> {
+    /// Finds the first tier applying to `module` with a pattern matching any of `names`.
+    ///
+    /// Patterns have to match a whole name, like the `contexts` of the `kubernetes` module.
+    pub fn classify(context: &'a Context, module: &str, names: &[Option<&str>]) -> Option<Self> {
+        let names = names.iter().flatten().collect::<Vec<_>>();
+        if names.is_empty() {
+            return None;
+        }
+
+        context
+            .root_config
+            .environment_tiers
+            .iter()
+            .filter(|(_, tier)| tier.modules.is_empty() || tier.modules.iter().any(|m| m == module))
+            .find(|(name, tier)| {
+                tier.patterns.iter().any(|pattern| {
+                    match Regex::new(&format!("^(?:{pattern})$")) {
+                        Ok(re) => names.iter().any(|name| re.is_match(name)),
+                        Err(error) => {
+                            log::warn!(
+                                "Could not compile regular expression `{pattern}` of environment tier `{name}`:\n{error}"
+                            );
+                            false
+                        }
+                    }
+                })
+            })
+            .map(|(name, config)| Self { name, config })
+    }
+
+    /// The symbol replacing the `symbol` of the module, if set
+    pub fn symbol(&self) -> Option<&'a str> {
+        self.config.symbol.as_deref()
+    }
+
+    /// The style replacing the `style` of the module, if set
+    pub fn style(&self) -> Option<&'a str> {
+        self.config.style.as_deref()
+    }
+}
+
+/// The `symbol` and `style` of a module, replaced by those of its tier if it has one
+pub trait TierOverride<'a> {
+    fn symbol_or<'b>(&self, symbol: &'b str) -> &'b str
+    where
+        'a: 'b;
+
+    fn style_or<'b>(&self, style: &'b str) -> &'b str
+    where
+        'a: 'b;
+}
+
+impl<'a> TierOverride<'a> for Option<Tier<'a>> {
+    fn symbol_or<'b>(&self, symbol: &'b str) -> &'b str
+    where
+        'a: 'b,
+    {
+        self.as_ref().and_then(Tier::symbol).unwrap_or(symbol)
+    }
+
+    fn style_or<'b>(&self, style: &'b str) -> &'b str
+    where
+        'a: 'b,
+    {
+        self.as_ref().and_then(Tier::style).unwrap_or(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::default_context;
+
+    fn tiers_context() -> Context<'static> {
+        default_context().set_config(toml::toml! {
+            [environment_tiers.prod]
+            patterns = ["prod|production", ".*-prd-.*"]
+            style = "bold red"
+            [environment_tiers.staging]
+            patterns = ["stag.*"]
+            modules = ["gcloud"]
+            [environment_tiers.broken]
+            patterns = ["(dev"]
+        })
+    }
+
+    #[test]
+    fn first_matching_tier() {
+        let context = tiers_context();
+
+        let tier = Tier::classify(&context, "aws", &[None, Some("acme-prd-eu")]).unwrap();
+        assert_eq!(tier.name, "prod");
+        assert_eq!(tier.style(), Some("bold red"));
+        assert_eq!(tier.symbol(), None);
+
+        let tier = Some(tier);
+        assert_eq!(tier.style_or("yellow"), "bold red");
+        assert_eq!(tier.symbol_or("☁️ "), "☁️ ");
+        assert_eq!(None::<Tier>.style_or("yellow"), "yellow");
+
+        assert_eq!(
+            Tier::classify(&context, "aws", &[Some("production")]).map(|tier| tier.name),
+            Some("prod")
+        );
+        // The pattern has to match the whole name
+        assert!(Tier::classify(&context, "aws", &[Some("preprod")]).is_none());
+        assert!(Tier::classify(&context, "aws", &[None]).is_none());
+    }
+
+    #[test]
+    fn tiers_limited_to_modules() {
+        let context = tiers_context();
+
+        assert!(Tier::classify(&context, "aws", &[Some("staging")]).is_none());
+        assert_eq!(
+            Tier::classify(&context, "gcloud", &[Some("staging")]).map(|tier| tier.name),
+            Some("staging")
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::borrow::Cow;
+use std::time::SystemTime;
+
+use super::utils::hashicorp::{alias, display_address};
+use super::utils::tier::{Tier, TierOverride};
+use super::{Context, Module, ModuleConfig};
+
+use crate::configs::vault::VaultConfig;
+use crate::formatter::StringFormatter;
+use crate::utils::render_time;
+
+/// Creates a module with the current Vault address and namespace
+///
+/// Will display the Vault address if `VAULT_ADDR` is set, and the namespace from `VAULT_NAMESPACE`.
+/// The age of the token in `~/.vault-token` is shown as well, unless `VAULT_TOKEN` overrides it.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("vault");
+    let config: VaultConfig = VaultConfig::try_load(module.config);
+
+    if !(context.detect_env_vars(&config.detect_env_vars)) {
+        return None;
+    }
+
+    let address = context.get_env("VAULT_ADDR")?;
+    let namespace = context.get_env("VAULT_NAMESPACE");
+    let token_age = get_token_age(context);
+
+    let tier = Tier::classify(context, "vault", &[namespace.as_deref(), Some(&address)]);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(tier.symbol_or(config.symbol)),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(tier.style_or(config.style))),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "address" => Some(Ok(Cow::Borrowed(alias(
+                    &config.address_aliases,
+                    display_address(&address),
+                )))),
+                "namespace" => namespace.as_deref().map(|namespace| {
+                    Ok(Cow::Borrowed(alias(&config.namespace_aliases, namespace)))
+                }),
+                "token_age" => {
+                    token_age.map(|age| Ok(Cow::Owned(render_time(u128::from(age) * 1000, false))))
+                }
+                "duration" => {
+                    if config.token_ttl == 0 {
+                        return None;
+                    }
+                    let remaining = config.token_ttl.saturating_sub(token_age?);
+                    Some(Ok(if remaining > 0 {
+                        Cow::Owned(render_time(u128::from(remaining) * 1000, false))
+                    } else {
+                        Cow::Borrowed(config.expiration_symbol)
+                    }))
+                }
+                "tier" => tier.as_ref().map(|tier| Ok(Cow::Borrowed(tier.name))),
+                _ => None,
+            })
+            .parse(None, Some(context))
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `vault`:\n{error}");
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+/// The age in seconds of the token `vault login` stored in `~/.vault-token`.
+///
+/// Vault does not record when the token expires, so the time it was written is the best hint
+/// without asking the server.
+fn get_token_age(context: &Context) -> Option<u64> {
+    if context.get_env("VAULT_TOKEN").is_some() {
+        return None;
+    }
+
+    let token_file = context.get_home()?.join(".vault-token");
+    let modified = token_file.metadata().ok()?.modified().ok()?;
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+    Some(age.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use nu_ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn not_set() {
+        let actual = ModuleRenderer::new("vault")
+            .env("VAULT_NAMESPACE", "admin")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn address_and_namespace() {
+        let actual = ModuleRenderer::new("vault")
+            .env("VAULT_ADDR", "https://vault.example.com:8200/")
+            .env("VAULT_NAMESPACE", "admin/team-a")
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow
+                .bold()
+                .paint("🔐 vault.example.com:8200/admin/team-a")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn aliases() {
+        let actual = ModuleRenderer::new("vault")
+            .env("VAULT_ADDR", "https://vault.example.com:8200")
+            .env("VAULT_NAMESPACE", "admin/team-a")
+            .config(toml::toml! {
+                [vault]
+                [vault.address_aliases]
+                "vault.example.com:8200" = "prod"
+                [vault.namespace_aliases]
+                "admin/team-a" = "a"
+            })
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🔐 prod/a")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn detect_env_vars() {
+        let actual = ModuleRenderer::new("vault")
+            .env("VAULT_ADDR", "https://vault.example.com")
+            .config(toml::toml! {
+                [vault]
+                detect_env_vars = ["VAULT_PROMPT"]
+            })
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn expired_token() -> io::Result<()> {
+        let (renderer, home) = ModuleRenderer::new_with_home("vault")?;
+        let token_file = File::create(home.path().join(".vault-token"))?;
+        token_file.set_modified(SystemTime::now() - Duration::from_secs(2 * 60 * 60))?;
+
+        let actual = renderer
+            .env("VAULT_ADDR", "http://127.0.0.1:8200")
+            .config(toml::toml! {
+                [vault]
+                format = "[$address( $duration)]($style)"
+                token_ttl = 3600
+            })
+            .collect();
+        let expected = Some(Color::Yellow.bold().paint("127.0.0.1:8200 X").to_string());
+
+        assert_eq!(expected, actual);
+        home.close()
+    }
+
+    #[test]
+    fn token_from_env() -> io::Result<()> {
+        let (renderer, home) = ModuleRenderer::new_with_home("vault")?;
+        File::create(home.path().join(".vault-token"))?;
+
+        let actual = renderer
+            .env("VAULT_ADDR", "http://127.0.0.1:8200")
+            .env("VAULT_TOKEN", "hvs.redacted")
+            .config(toml::toml! {
+                [vault]
+                format = "[$address( $token_age)( $duration)]($style)"
+                token_ttl = 3600
+            })
+            .collect();
+        let expected = Some(Color::Yellow.bold().paint("127.0.0.1:8200").to_string());
+
+        assert_eq!(expected, actual);
+        home.close()
+    }
+}
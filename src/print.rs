@@ -2,17 +2,19 @@ use clap::{ValueEnum, builder::PossibleValue};
 use nu_ansi_term::AnsiStrings;
 use rayon::prelude::*;
 use regex::Regex;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Debug, Write as FmtWrite};
 use std::io::{self, Write};
-use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 use terminal_size::terminal_size;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthChar;
 
+use crate::config::ModuleConfig;
 use crate::configs::PROMPT_ORDER;
+use crate::configs::directory::DirectoryConfig;
 use crate::context::{Context, Properties, Shell, Target};
 use crate::formatter::{StringFormatter, VariableHolder};
 use crate::module::ALL_MODULES;
@@ -20,7 +22,7 @@ use crate::module::Module;
 use crate::modules;
 use crate::segment::Segment;
 use crate::shadow;
-use crate::utils::wrap_colorseq_for_shell;
+use crate::utils::{wrap_colorseq_for_shell, wrap_oscseq_for_shell};
 
 pub struct Grapheme<'a>(pub &'a str);
 
@@ -95,38 +97,49 @@ pub fn get_prompt(context: &Context) -> String {
         buf.push_str("\x1b[J"); // An ASCII control code to clear screen
     }
 
-    let (formatter, modules) = load_formatter_and_modules(context);
-
-    let formatter = formatter.map_variables_to_segments(|module| {
-        // Make $all display all modules not explicitly referenced
-        if module == "all" {
-            Some(Ok(all_modules_uniq(&modules)
-                .par_iter()
-                .flat_map(|module| {
-                    handle_module(module, context, &modules)
-                        .into_iter()
-                        .flat_map(|module| module.segments)
-                        .collect::<Vec<Segment>>()
-                })
-                .collect::<Vec<_>>()))
-        } else if context.is_module_disabled_in_config(module) {
-            None
-        } else {
-            // Get segments from module
-            Some(Ok(handle_module(module, context, &modules)
-                .into_iter()
-                .flat_map(|module| module.segments)
-                .collect::<Vec<Segment>>()))
-        }
-    });
+    // Shell integration sequences are only meaningful for the primary prompt
+    let integrates_shell = context.target == Target::Main;
+    // Only the Bash and Zsh init scripts mark the command output, to balance the prompt marks
+    let marks_prompt = integrates_shell
+        && config.semantic_prompt
+        && matches!(context.shell, Shell::Bash | Shell::Zsh);
 
     // Creates a root module and prints it.
+    // The rendered modules are kept, so the title does not need to render them again.
+    let rendered = RenderedModules::default();
     let mut root_module = Module::new("Starship Root", "The root module", None);
-    root_module.set_segments(
-        formatter
-            .parse(None, Some(context))
-            .expect("Unexpected error returned in root format variables"),
-    );
+    root_module.set_segments(if directory_truncates_to_width(context) {
+        width_aware_root_segments(context, &rendered)
+    } else {
+        root_segments(context, |module, modules| {
+            render_module_once(module, context, modules, &rendered)
+        })
+    });
+
+    if integrates_shell && config.report_directory {
+        let host = whoami::fallible::hostname().unwrap_or_default();
+        let osc = format!("\x1b]7;{}\x07", file_url(&host, &context.logical_dir));
+        buf.push_str(&wrap_oscseq_for_shell(osc, context.shell));
+    }
+    if integrates_shell && !config.title.format.is_empty() {
+        if let Some(title) = render_title(context, &config.title.format, None, &rendered) {
+            let osc = format!("\x1b]0;{title}\x07");
+            buf.push_str(&wrap_oscseq_for_shell(osc, context.shell));
+        }
+        // Only the Bash and Zsh init scripts update the title while a command runs,
+        // and they only need to run `starship title` if there is a format for it
+        if !config.title.running_format.is_empty()
+            && matches!(context.shell, Shell::Bash | Shell::Zsh)
+        {
+            buf.push_str(RUNNING_TITLE_MARK);
+        }
+    }
+    if marks_prompt {
+        buf.push_str(&wrap_oscseq_for_shell(
+            String::from(PROMPT_START_MARK),
+            context.shell,
+        ));
+    }
 
     let module_strings = root_module.ansi_strings_for_width(Some(context.width));
     if config.add_newline && context.target != Target::Continuation {
@@ -139,6 +152,13 @@ pub fn get_prompt(context: &Context) -> String {
         wrap_colorseq_for_shell(AnsiStrings(&module_strings).to_string(), context.shell);
     write!(buf, "{shell_wrapped_output}").unwrap();
 
+    if marks_prompt {
+        buf.push_str(&wrap_oscseq_for_shell(
+            String::from(PROMPT_END_MARK),
+            context.shell,
+        ));
+    }
+
     if context.target == Target::Right {
         // right prompts generally do not allow newlines
         buf = buf.replace('\n', "");
@@ -154,6 +174,94 @@ pub fn get_prompt(context: &Context) -> String {
     buf
 }
 
+/// OSC 133 mark for the start of the prompt, also looked for by the init scripts
+const PROMPT_START_MARK: &str = "\x1b]133;A\x07";
+
+/// OSC 133 mark for the end of the prompt and the start of the command line
+const PROMPT_END_MARK: &str = "\x1b]133;B\x07";
+
+/// Private APC sequence asking the init scripts to set the title while a command runs.
+/// They remove it from the prompt, and terminals ignore it otherwise.
+const RUNNING_TITLE_MARK: &str = "\x1b_starship:running-title\x1b\\";
+
+/// Builds the `file://` URL of a directory as expected by OSC 7
+fn file_url(host: &str, dir: &Path) -> String {
+    let mut url = format!("file://{host}");
+    let path = dir.as_os_str().as_encoded_bytes();
+    // Windows paths like `C:\Users` become `/C:/Users`
+    if path.first() != Some(&b'/') {
+        url.push('/');
+    }
+    for &byte in path {
+        match byte {
+            b'\\' if cfg!(windows) => url.push('/'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                url.push(byte as char);
+            }
+            _ => write!(url, "%{byte:02X}").unwrap(),
+        }
+    }
+    url
+}
+
+/// Renders a terminal title format with the modules it references
+///
+/// `$command` is replaced by the running command, if any.
+/// Modules already in `rendered` are reused instead of being rendered again.
+fn render_title(
+    context: &Context,
+    format: &str,
+    command: Option<&str>,
+    rendered: &RenderedModules,
+) -> Option<String> {
+    let formatter = StringFormatter::new(format)
+        .map_err(|e| log::error!("Error parsing title format: {e}"))
+        .ok()?;
+    let modules = formatter
+        .get_variables()
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    let segments = formatter
+        .map(|variable| match variable {
+            "command" => command.map(Ok),
+            _ => None,
+        })
+        .map_variables_to_segments(|module| {
+            if context.is_module_disabled_in_config(module) {
+                None
+            } else {
+                Some(Ok(render_module_once(module, context, &modules, rendered)))
+            }
+        })
+        .parse(None, Some(context))
+        .map_err(|e| log::error!("Error rendering title: {e}"))
+        .ok()?;
+
+    // Titles are plain text on a single line
+    let title = segments
+        .iter()
+        .map(Segment::value)
+        .collect::<String>()
+        .replace(char::is_control, " ");
+    Some(title.trim().to_string())
+}
+
+/// Prints the terminal title while a command runs, as configured by `title.running_format`
+pub fn title(args: Properties, command: Option<String>) {
+    let mut context = Context::new(args, Target::Main);
+    // The title is written straight to the terminal instead of a prompt, so nothing needs escaping
+    context.shell = Shell::Unknown;
+
+    let format = &context.root_config.title.running_format;
+    if format.is_empty() {
+        return;
+    }
+    let rendered = RenderedModules::default();
+    if let Some(title) = render_title(&context, format, command.as_deref(), &rendered) {
+        print!("\x1b]0;{title}\x07");
+    }
+}
+
 pub fn module(module_name: &str, args: Properties) {
     let context = Context::new(args, Target::Main);
     let module = get_module(module_name, &context).unwrap_or_default();
@@ -189,7 +297,7 @@ pub fn timings(args: Properties) {
         })
         .collect::<Vec<ModuleTiming>>();
 
-    modules.sort_by(|a, b| b.duration.cmp(&a.duration));
+    modules.sort_by_key(|m| std::cmp::Reverse(m.duration));
 
     let max_name_width = modules.iter().map(|i| i.name_len).max().unwrap_or(0);
     let max_duration_width = modules.iter().map(|i| i.duration_len).max().unwrap_or(0);
@@ -326,7 +434,7 @@ fn compute_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
     prompt_order
 }
 
-fn handle_module<'a>(
+pub(crate) fn handle_module<'a>(
     module: &str,
     context: &'a Context,
     module_list: &BTreeSet<String>,
@@ -338,10 +446,14 @@ fn handle_module<'a>(
         if !context.is_module_disabled_in_config(module) {
             modules.extend(modules::handle(module, context));
         }
-    } else if module.starts_with("custom.") || module.starts_with("env_var.") {
-        // custom.<name> and env_var.<name> are special cases and handle disabled modules themselves
+    } else if module.starts_with("custom.")
+        || module.starts_with("env_var.")
+        || module.starts_with("config_value.")
+    {
+        // custom.<name>, env_var.<name> and config_value.<name> are special cases and handle
+        // disabled modules themselves
         modules.extend(modules::handle(module, context));
-    } else if matches!(module, "custom" | "env_var") {
+    } else if matches!(module, "custom" | "env_var" | "config_value") {
         // env var is a spacial case and may contain a top-level module definition
         if module == "env_var" {
             modules.extend(modules::handle(module, context));
@@ -379,6 +491,110 @@ fn handle_module<'a>(
     modules
 }
 
+/// Renders the root format, using `render_module` to get the segments of each module
+fn root_segments<F>(context: &Context, render_module: F) -> Vec<Segment>
+where
+    F: Fn(&str, &BTreeSet<String>) -> Vec<Segment> + Sync,
+{
+    let (formatter, modules) = load_formatter_and_modules(context);
+
+    let formatter = formatter.map_variables_to_segments(|module| {
+        // Make $all display all modules not explicitly referenced
+        if module == "all" {
+            Some(Ok(all_modules_uniq(&modules)
+                .par_iter()
+                .flat_map(|module| render_module(module, &modules))
+                .collect::<Vec<_>>()))
+        } else if context.is_module_disabled_in_config(module) {
+            None
+        } else {
+            // Get segments from module
+            Some(Ok(render_module(module, &modules)))
+        }
+    });
+
+    formatter
+        .parse(None, Some(context))
+        .expect("Unexpected error returned in root format variables")
+}
+
+fn module_segments(modules: Vec<Module>) -> Vec<Segment> {
+    modules
+        .into_iter()
+        .flat_map(|module| module.segments)
+        .collect()
+}
+
+/// Segments of the modules rendered so far, by module name
+type RenderedModules = Mutex<HashMap<String, Vec<Segment>>>;
+
+/// Renders a module, or reuses its segments if it was rendered before
+fn render_module_once(
+    module: &str,
+    context: &Context,
+    module_list: &BTreeSet<String>,
+    rendered: &RenderedModules,
+) -> Vec<Segment> {
+    if let Some(segments) = rendered.lock().unwrap().get(module) {
+        return segments.clone();
+    }
+    let segments = module_segments(handle_module(module, context, module_list));
+    rendered
+        .lock()
+        .unwrap()
+        .insert(module.to_string(), segments.clone());
+    segments
+}
+
+/// Whether the directory module should be shortened to the width left on its line
+fn directory_truncates_to_width(context: &Context) -> bool {
+    context.width > 0
+        && matches!(context.target, Target::Main | Target::Profile(_))
+        && !context.is_module_disabled_in_config("directory")
+        && DirectoryConfig::try_load(context.config.get_module_config("directory"))
+            .truncate_to_width
+}
+
+/// Renders the root format in two passes
+///
+/// The first pass renders every module except `directory`, which is replaced by
+/// a placeholder to find the width the other modules take on its line.
+/// The second pass reuses those modules and renders `directory` with the remaining width.
+fn width_aware_root_segments(context: &Context, rendered: &RenderedModules) -> Vec<Segment> {
+    const DIRECTORY_PLACEHOLDER: &str = "\0directory\0";
+
+    let segments = root_segments(context, |module, modules| {
+        if module == "directory" {
+            return Segment::from_text(None, DIRECTORY_PLACEHOLDER);
+        }
+        render_module_once(module, context, modules, rendered)
+    });
+
+    let Some(used_width) = segments
+        .split(|segment| matches!(segment, Segment::LineTerm))
+        .find(|line| {
+            line.iter()
+                .any(|segment| segment.value() == DIRECTORY_PLACEHOLDER)
+        })
+        .map(|line| {
+            line.iter()
+                .filter(|segment| {
+                    matches!(segment, Segment::Text(_)) && segment.value() != DIRECTORY_PLACEHOLDER
+                })
+                .map(Segment::width_graphemes)
+                .sum::<usize>()
+        })
+    else {
+        // `directory` is not part of the prompt, keep the first pass
+        return segments;
+    };
+    context.set_directory_width(context.width.saturating_sub(used_width));
+
+    root_segments(context, |module, modules| {
+        render_module_once(module, context, modules, rendered)
+    })
+}
+
 fn should_add_implicit_module(
     parent_module: &str,
     child_module: &str,
@@ -492,10 +708,46 @@ fn load_formatter_and_modules<'a>(context: &'a Context) -> (StringFormatter<'a>,
 
 #[cfg(feature = "config-schema")]
 pub fn print_schema() {
-    let schema = schemars::schema_for!(crate::configs::FullConfig);
+    let mut schema = schemars::schema_for!(crate::configs::FullConfig).to_value();
+    add_module_timeouts(&mut schema);
     println!("{}", serde_json::to_string_pretty(&schema).unwrap());
 }
 
+/// Adds the `timeout` every module accepts to the schema of the module configs,
+/// which don't have it as a field because it is applied by `modules::handle`.
+#[cfg(any(test, feature = "config-schema"))]
+fn add_module_timeouts(schema: &mut serde_json::Value) {
+    let module_defs = schema["properties"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(name, _)| {
+            ALL_MODULES.contains(&name.as_str())
+                || matches!(name.as_str(), "custom" | "env_var" | "config_value")
+        })
+        .filter_map(|(_, property)| {
+            property
+                .get("$ref")
+                .or_else(|| property.get("additionalProperties")?.get("$ref"))?
+                .as_str()?
+                .strip_prefix("#/$defs/")
+                .map(str::to_string)
+        })
+        .collect::<BTreeSet<_>>();
+
+    for name in module_defs {
+        let properties = schema
+            .get_mut("$defs")
+            .and_then(|defs| defs.get_mut(&name)?.get_mut("properties")?.as_object_mut());
+        if let Some(properties) = properties {
+            properties.insert(
+                "timeout".to_string(),
+                serde_json::json!({ "type": "integer", "format": "uint64", "minimum": 0 }),
+            );
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Preset(pub &'static str);
 
@@ -557,6 +809,164 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn main_prompt_semantic_marks() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                semantic_prompt=true
+                format="$character"
+                [character]
+                format=">"
+        });
+        context.target = Target::Main;
+        context.shell = Shell::Bash;
+
+        let expected = String::from("\\[\x1b]133;A\x07\\]>\\[\x1b]133;B\x07\\]");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn semantic_marks_only_for_shells_marking_output() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                semantic_prompt=true
+                format="$character"
+                [character]
+                format=">"
+        });
+        context.target = Target::Main;
+        context.shell = Shell::Fish;
+
+        // Fish still gets the sequence clearing the screen, but no semantic prompt marks
+        let expected = String::from("\x1b[J>");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn right_prompt_no_semantic_marks() {
+        let mut context = default_context().set_config(toml::toml! {
+                semantic_prompt=true
+                report_directory=true
+                right_format="$character"
+                [character]
+                format=">"
+        });
+        context.target = Target::Right;
+
+        let expected = String::from(">");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn main_prompt_reports_directory() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                report_directory=true
+                format="$character"
+                [character]
+                format=">"
+        });
+        context.target = Target::Main;
+        context.shell = Shell::Zsh;
+        context.logical_dir = PathBuf::from("/tmp/my dir");
+
+        let host = whoami::fallible::hostname().unwrap_or_default();
+        let expected = format!("%{{\x1b]7;file://{host}/tmp/my%20dir\x07%}}>");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn main_prompt_sets_title() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                format="$character"
+                [character]
+                format=">"
+                [title]
+                format="$character — starship"
+        });
+        context.target = Target::Main;
+        context.shell = Shell::Bash;
+
+        let expected = String::from("\\[\x1b]0;> — starship\x07\\]>");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn main_prompt_requests_running_title() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                format="$character"
+                [character]
+                format=">"
+                [title]
+                format="starship"
+                running_format="$command"
+        });
+        context.target = Target::Main;
+        context.shell = Shell::Zsh;
+
+        let expected = format!("%{{\x1b]0;starship\x07%}}{RUNNING_TITLE_MARK}>");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn title_reuses_rendered_modules() {
+        let context = default_context().set_config(toml::toml! {
+                [character]
+                format=">"
+        });
+        let rendered = RenderedModules::default();
+        rendered.lock().unwrap().insert(
+            String::from("character"),
+            Segment::from_text(None, "rendered"),
+        );
+
+        let expected = Some(String::from("rendered"));
+        let actual = render_title(&context, "$character", None, &rendered);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn running_title() {
+        let context = default_context().set_config(toml::toml! {
+                [character]
+                format="[>](bold red)\n"
+        });
+
+        let expected = Some(String::from("make test >"));
+        let actual = render_title(
+            &context,
+            "$command $character",
+            Some("make\ttest"),
+            &RenderedModules::default(),
+        );
+        assert_eq!(expected, actual);
+        let expected = Some(String::from(">"));
+        let actual = render_title(
+            &context,
+            "$command $character",
+            None,
+            &RenderedModules::default(),
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn file_url_encoding() {
+        assert_eq!(
+            file_url("box", Path::new("/home/ünïcode/100% done")),
+            "file://box/home/%C3%BCn%C3%AFcode/100%25%20done"
+        );
+        assert_eq!(file_url("", Path::new("/")), "file:///");
+    }
+
     #[test]
     fn right_prompt() {
         let mut context = default_context().set_config(toml::toml! {
@@ -652,6 +1062,46 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn directory_truncated_to_width() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                format="$directory$character"
+                [directory]
+                format="$path "
+                truncate_to_width=true
+                use_os_path_sep=false
+                [character]
+                format=">"
+        });
+        context.logical_dir = PathBuf::from("/starship/engines/booster/rocket");
+        context.width = 20;
+
+        let expected = String::from("/s/e/b/rocket >");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn directory_truncated_to_width_ignores_other_lines() {
+        let mut context = default_context().set_config(toml::toml! {
+                add_newline=false
+                format="$directory$line_break$character"
+                [directory]
+                format="$path"
+                truncate_to_width=true
+                use_os_path_sep=false
+                [character]
+                format=">>>>>>>>>>"
+        });
+        context.logical_dir = PathBuf::from("/starship/engines/booster/rocket");
+        context.width = 19;
+
+        let expected = String::from("/s/e/booster/rocket\n>>>>>>>>>>");
+        let actual = get_prompt(&context);
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn preset_list_returns_one_or_more_items() {
         assert!(preset_list().lines().count() > 0);
@@ -684,6 +1134,40 @@ mod test {
         print_schema();
     }
 
+    #[test]
+    fn module_timeouts_in_schema() {
+        let mut schema = serde_json::json!({
+            "properties": {
+                "format": { "type": "string" },
+                "rust": { "$ref": "#/$defs/RustConfig" },
+                "custom": { "additionalProperties": { "$ref": "#/$defs/CustomConfig" } },
+                "title": { "$ref": "#/$defs/TitleConfig" },
+            },
+            "$defs": {
+                "RustConfig": { "properties": { "disabled": { "type": "boolean" } } },
+                "CustomConfig": { "properties": {} },
+                "TitleConfig": { "properties": {} },
+            },
+        });
+
+        add_module_timeouts(&mut schema);
+
+        let timeout = serde_json::json!({ "type": "integer", "format": "uint64", "minimum": 0 });
+        assert_eq!(
+            schema["$defs"]["RustConfig"]["properties"]["timeout"],
+            timeout
+        );
+        assert_eq!(
+            schema["$defs"]["CustomConfig"]["properties"]["timeout"],
+            timeout
+        );
+        assert!(
+            schema["$defs"]["TitleConfig"]["properties"]
+                .get("timeout")
+                .is_none()
+        );
+    }
+
     #[test]
     fn custom_expands() -> std::io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -3,10 +3,11 @@ use crate::{
     print::{Grapheme, UnicodeWidthGraphemes},
 };
 use nu_ansi_term::{AnsiString, Style as AnsiStyle};
+use serde::{Deserialize, Serialize};
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Type that holds text with an associated style
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct TextSegment {
     /// The segment's style. If None, will inherit the style of the module containing it.
     style: Option<Style>,
@@ -26,7 +27,7 @@ impl TextSegment {
 }
 
 /// Type that holds fill text with an associated style
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct FillSegment {
     /// The segment's style. If None, will inherit the style of the module containing it.
     style: Option<Style>,
@@ -87,7 +88,7 @@ mod fill_seg_tests {
 }
 
 /// A segment is a styled text chunk ready for printing.
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub enum Segment {
     Text(TextSegment),
     Fill(FillSegment),
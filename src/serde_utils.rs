@@ -53,6 +53,8 @@ pub struct ValueDeserializer<'de> {
 struct StructInfo {
     fields: &'static [&'static str],
     name: &'static str,
+    /// Whether the struct is the configuration of a module, rather than one nested in it
+    is_module: bool,
 }
 
 impl<'de> ValueDeserializer<'de> {
@@ -155,7 +157,13 @@ impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        self.info = Some(StructInfo { fields, name });
+        // Module configurations are deserialized on their own, nested structs under a key
+        let is_module = self.current_key.is_none() && name != "StarshipRootConfig";
+        self.info = Some(StructInfo {
+            fields,
+            name,
+            is_module,
+        });
         self.deserialize_any(visitor)
     }
 
@@ -176,7 +184,18 @@ impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
             .info
             .filter(|StructInfo { name, .. }| name == &"StarshipRootConfig")
             .and(self.current_key)
-            .is_some_and(|key| ALL_MODULES.contains(&key) || key == "custom" || key == "env_var")
+            .is_some_and(|key| {
+                ALL_MODULES.contains(&key) || matches!(key, "custom" | "env_var" | "config_value")
+            })
+        {
+            return visitor.visit_none();
+        }
+
+        // Every module accepts a `timeout`, which is applied when the module is computed
+        if self.current_key == Some("timeout")
+            && self
+                .info
+                .is_some_and(|StructInfo { is_module, .. }| is_module)
         {
             return visitor.visit_none();
         }
@@ -401,6 +420,52 @@ mod test {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_deserialize_module_timeout() {
+        let value = toml::toml! {
+            foo = "bar"
+            timeout = 100
+        };
+        let deserializer = ValueDeserializer::new(&value);
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct SampleConfig {
+            foo: String,
+        }
+
+        assert!(SampleConfig::deserialize(deserializer).is_ok());
+
+        let value = toml::toml! {
+            [nested]
+            foo = "bar"
+            timeout = 100
+        };
+        let deserializer = ValueDeserializer::new(&value);
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct OuterConfig {
+            nested: SampleConfig,
+        }
+
+        let result = OuterConfig::deserialize(deserializer).unwrap_err();
+        assert_eq!(
+            format!("{result}"),
+            "Error in 'Sample' at 'nested': Error in 'Sample' at 'timeout': Unknown key"
+        );
+
+        let value = toml::toml! {
+            timeout = 100
+        };
+        let deserializer = ValueDeserializer::new(&value);
+        let result = StarshipRootConfig::deserialize(deserializer).unwrap_err();
+        assert_eq!(
+            format!("{result}"),
+            "Error in 'StarshipRoot' at 'timeout': Unknown key (Did you mean 'scan_timeout'?)"
+        );
+    }
+
     #[test]
     fn test_deserialize_unknown_typo() {
         let value = toml::toml! {
@@ -0,0 +1,255 @@
+use clap::ValueEnum;
+use nu_ansi_term::{Color, Style};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+use crate::context::{Context, Properties, Shell, Target};
+use crate::module::Module;
+use crate::print::handle_module;
+
+/// The output format of `starship status-bar`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StatusBarFormat {
+    /// A tmux format string, e.g. for `status-right`
+    Tmux,
+    /// A JSON array of modules and their styled segments, e.g. for a zellij plugin
+    Json,
+}
+
+#[derive(Serialize)]
+struct JsonModule<'a> {
+    name: &'a str,
+    text: String,
+    segments: Vec<JsonSegment<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonSegment<'a> {
+    text: &'a str,
+    fg: Option<String>,
+    bg: Option<String>,
+    bold: bool,
+    dimmed: bool,
+    italic: bool,
+    underline: bool,
+    reverse: bool,
+    strikethrough: bool,
+}
+
+/// Prints the modules listed in `status_bar.modules` for a terminal multiplexer status bar
+pub fn status_bar(args: Properties, format: StatusBarFormat) {
+    let mut context = Context::new(args, Target::Main);
+    // The output is read by the multiplexer instead of a shell, so nothing needs escaping
+    context.shell = Shell::Unknown;
+
+    let modules = render_modules(&context);
+    match format {
+        StatusBarFormat::Tmux => print!("{}", tmux_string(&modules)),
+        StatusBarFormat::Json => println!("{}", json_string(&modules)),
+    }
+}
+
+fn render_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
+    let names = &context.root_config.status_bar.modules;
+    let module_list = names.iter().cloned().collect::<BTreeSet<_>>();
+
+    names
+        .par_iter()
+        .flat_map(|name| handle_module(name, context, &module_list))
+        .filter(|module| !module.is_empty())
+        .collect()
+}
+
+/// Status bars are a single line of text
+fn sanitize(text: &str) -> String {
+    text.replace(char::is_control, " ")
+}
+
+/// Renders the modules with tmux `#[...]` style directives instead of ANSI escape sequences
+fn tmux_string(modules: &[Module]) -> String {
+    let mut output = String::new();
+    let mut current = None;
+
+    for segment in modules.iter().flat_map(|module| &module.segments) {
+        let text = sanitize(segment.value());
+        if text.is_empty() {
+            continue;
+        }
+
+        let style = segment.style().filter(|style| *style != Style::default());
+        if style != current {
+            output.push_str(&tmux_style(style.as_ref()));
+            current = style;
+        }
+        // `#` starts a format sequence in tmux
+        output.push_str(&text.replace('#', "##"));
+    }
+
+    if current.is_some() {
+        output.push_str("#[default]");
+    }
+    output
+}
+
+/// Converts a style to tmux `#[...]` directives, resetting the previous style first
+fn tmux_style(style: Option<&Style>) -> String {
+    let Some(style) = style else {
+        return "#[default]".to_string();
+    };
+
+    let mut attributes = Vec::new();
+    if let Some(fg) = style.foreground {
+        attributes.push(format!("fg={}", color_name(fg)));
+    }
+    if let Some(bg) = style.background {
+        attributes.push(format!("bg={}", color_name(bg)));
+    }
+    for (enabled, attribute) in [
+        (style.is_bold, "bold"),
+        (style.is_dimmed, "dim"),
+        (style.is_italic, "italics"),
+        (style.is_underline, "underscore"),
+        (style.is_blink, "blink"),
+        (style.is_reverse, "reverse"),
+        (style.is_hidden, "hidden"),
+        (style.is_strikethrough, "strikethrough"),
+    ] {
+        if enabled {
+            attributes.push(attribute.to_string());
+        }
+    }
+
+    format!("#[default]#[{}]", attributes.join(","))
+}
+
+/// The name of a color, as understood by tmux
+fn color_name(color: Color) -> String {
+    match color {
+        Color::Black => "black".to_string(),
+        Color::Red => "red".to_string(),
+        Color::Green => "green".to_string(),
+        Color::Yellow => "yellow".to_string(),
+        Color::Blue => "blue".to_string(),
+        Color::Purple | Color::Magenta => "magenta".to_string(),
+        Color::Cyan => "cyan".to_string(),
+        Color::White => "white".to_string(),
+        Color::DarkGray => "brightblack".to_string(),
+        Color::LightRed => "brightred".to_string(),
+        Color::LightGreen => "brightgreen".to_string(),
+        Color::LightYellow => "brightyellow".to_string(),
+        Color::LightBlue => "brightblue".to_string(),
+        Color::LightPurple | Color::LightMagenta => "brightmagenta".to_string(),
+        Color::LightCyan => "brightcyan".to_string(),
+        Color::LightGray => "brightwhite".to_string(),
+        Color::Fixed(num) => format!("colour{num}"),
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Default => "default".to_string(),
+    }
+}
+
+/// Renders the modules as JSON, leaving the styling to the consumer
+fn json_string(modules: &[Module]) -> String {
+    let modules = modules
+        .iter()
+        .map(|module| JsonModule {
+            name: module.get_name(),
+            text: sanitize(&module.get_segments().concat()),
+            segments: module
+                .segments
+                .iter()
+                .map(|segment| {
+                    let style = segment.style().unwrap_or_default();
+                    JsonSegment {
+                        text: segment.value(),
+                        fg: style.foreground.map(color_name),
+                        bg: style.background.map(color_name),
+                        bold: style.is_bold,
+                        dimmed: style.is_dimmed,
+                        italic: style.is_italic,
+                        underline: style.is_underline,
+                        reverse: style.is_reverse,
+                        strikethrough: style.is_strikethrough,
+                    }
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string(&modules).expect("status bar modules are serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::default_context;
+
+    fn status_bar_context(modules: &[&str]) -> Context<'static> {
+        let mut context = default_context().set_config(toml::toml! {
+            [env_var.CLUSTER]
+            format = "[#$env_value]($style) "
+            style = "bold fg:#ff8700 bg:23"
+            [env_var.REGION]
+            format = "[$env_value](italic red)"
+        });
+        context.env.insert("CLUSTER", "prod-eu".to_string());
+        context.env.insert("REGION", "eu-west-1".to_string());
+        context.root_config.status_bar.modules = modules.iter().map(ToString::to_string).collect();
+        context
+    }
+
+    #[test]
+    fn tmux_styles() {
+        let context = status_bar_context(&["env_var.CLUSTER", "env_var.REGION"]);
+
+        let actual = tmux_string(&render_modules(&context));
+        let expected = "#[default]#[fg=#ff8700,bg=colour23,bold]##prod-eu#[default] \
+                        #[default]#[fg=red,italics]eu-west-1#[default]";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn tmux_empty() {
+        let context = status_bar_context(&[]);
+        assert_eq!(tmux_string(&render_modules(&context)), "");
+
+        let context = status_bar_context(&["env_var.MISSING"]);
+        assert_eq!(tmux_string(&render_modules(&context)), "");
+    }
+
+    #[test]
+    fn json_modules() {
+        let context = status_bar_context(&["env_var.REGION", "env_var.CLUSTER"]);
+
+        let actual: serde_json::Value =
+            serde_json::from_str(&json_string(&render_modules(&context))).unwrap();
+        let names_and_texts = actual
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|module| (module["name"].as_str(), module["text"].as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names_and_texts,
+            [
+                (Some("env_var.REGION"), Some("eu-west-1")),
+                (Some("env_var.CLUSTER"), Some("#prod-eu ")),
+            ]
+        );
+
+        let expected = serde_json::json!([{
+            "text": "eu-west-1",
+            "fg": "red",
+            "bg": null,
+            "bold": false,
+            "dimmed": false,
+            "italic": true,
+            "underline": false,
+            "reverse": false,
+            "strikethrough": false,
+        }]);
+        assert_eq!(actual[0]["segments"], expected);
+        assert_eq!(actual[1]["segments"][1]["fg"], "#ff8700");
+        assert_eq!(actual[1]["segments"][1]["bg"], "colour23");
+    }
+}
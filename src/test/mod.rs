@@ -148,6 +148,11 @@ impl<'a> ModuleRenderer<'a> {
         self
     }
 
+    pub fn fs_type(mut self, fs_type: &str) -> Self {
+        self.context.set_fs_type(fs_type);
+        self
+    }
+
     #[cfg(feature = "battery")]
     pub fn battery_info_provider(
         mut self,
@@ -48,6 +48,26 @@ pub fn read_file<P: AsRef<Path> + Debug>(file_name: P) -> Result<String> {
     result
 }
 
+/// Replaces the contents of a file, creating its directory if needed.
+///
+/// The contents are written to a temporary file next to it first, so that other starship
+/// processes reading the file concurrently never see it partially written.
+pub fn replace_file(path: &Path, contents: &[u8]) -> Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    std::fs::create_dir_all(dir)?;
+
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(path.file_name().unwrap_or_default());
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = dir.join(temp_name);
+
+    std::fs::write(&temp_path, contents)
+        .and_then(|()| std::fs::rename(&temp_path, path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&temp_path);
+        })
+}
+
 /// Write a string to a file
 #[cfg(test)]
 pub fn write_file<P: AsRef<Path>, S: AsRef<str>>(file_name: P, text: S) -> Result<()> {
@@ -581,6 +601,13 @@ pub fn wrap_colorseq_for_shell(ansi: String, shell: Shell) -> String {
     wrap_seq_for_shell(ansi, shell, ESCAPE_BEGIN, ESCAPE_END)
 }
 
+/// Wraps BEL-terminated OSC escape sequences in the shell-appropriate wrappers.
+pub fn wrap_oscseq_for_shell(osc: String, shell: Shell) -> String {
+    const ESCAPE_BEGIN: char = '\u{1b}';
+    const ESCAPE_END: char = '\u{7}';
+    wrap_seq_for_shell(osc, shell, ESCAPE_BEGIN, ESCAPE_END)
+}
+
 /// Many shells cannot deal with raw unprintable characters and miscompute the cursor position,
 /// leading to strange visual bugs like duplicated/missing chars. This function wraps a specified
 /// sequence in shell-specific escapes to avoid these problems.
@@ -679,7 +706,7 @@ pub fn exec_timeout(cmd: &mut Command, time_limit: Duration) -> Option<CommandOu
         Ok(None) => {
             log::warn!("Executing command {:?} timed out.", cmd.get_program());
             log::warn!(
-                "You can set command_timeout in your config to a higher value to allow longer-running commands to keep executing."
+                "You can set command_timeout or the module's timeout in your config to a higher value to allow longer-running commands to keep executing."
             );
             None
         }
@@ -757,6 +784,8 @@ pub fn encode_to_hex(slice: &[u8]) -> String {
 pub trait PathExt {
     /// Get device / volume info
     fn device_id(&self) -> Option<u64>;
+    /// Get the type of the file system the path is on, e.g. `ext4` or `nfs4`
+    fn fs_type(&self) -> Option<String>;
 }
 
 #[cfg(windows)]
@@ -765,6 +794,10 @@ impl PathExt for Path {
         // Maybe it should use unimplemented!
         Some(42u64)
     }
+
+    fn fs_type(&self) -> Option<String> {
+        None
+    }
 }
 
 #[cfg(not(windows))]
@@ -780,12 +813,80 @@ impl PathExt for Path {
         use std::os::unix::fs::MetadataExt;
         Some(self.metadata().ok()?.dev())
     }
+
+    #[cfg(target_os = "linux")]
+    fn fs_type(&self) -> Option<String> {
+        let mountinfo = read_file("/proc/self/mountinfo").ok()?;
+        fs_type_from_mountinfo(&mountinfo, self)
+    }
+
+    #[cfg(all(unix, not(target_os = "linux")))]
+    fn fs_type(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Find the file system type of the mount containing `path` in the contents of `/proc/self/mountinfo`
+///
+/// Each line describes a mount, e.g.
+/// `36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue`,
+/// where the fifth field is the mount point and the first field after `-` is the file system type.
+/// Later mounts on the same mount point shadow earlier ones.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn fs_type_from_mountinfo(mountinfo: &str, path: &Path) -> Option<String> {
+    mountinfo
+        .lines()
+        .filter_map(|line| {
+            let (mount, fs) = line.split_once(" - ")?;
+            let mount_point = PathBuf::from(unescape_mountinfo(mount.split(' ').nth(4)?));
+            let fs_type = fs.split(' ').next()?;
+            path.starts_with(&mount_point)
+                .then_some((mount_point, fs_type))
+        })
+        .max_by_key(|(mount_point, _)| mount_point.components().count())
+        .map(|(_, fs_type)| fs_type.to_string())
+}
+
+/// Decode the octal escapes (e.g. `\040` for a space) used for paths in `/proc/self/mountinfo`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn unescape_mountinfo(field: &str) -> String {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut rest = field;
+    while let Some(index) = rest.find('\\') {
+        unescaped.push_str(&rest[..index]);
+        let escape = rest.get(index + 1..index + 4);
+        match escape.and_then(|code| u8::from_str_radix(code, 8).ok()) {
+            Some(byte) => {
+                unescaped.push(char::from(byte));
+                rest = &rest[index + 4..];
+            }
+            None => {
+                unescaped.push('\\');
+                rest = &rest[index + 1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn replace_file_creates_and_replaces() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cache").join("history.json");
+
+        replace_file(&path, b"first")?;
+        replace_file(&path, b"second")?;
+        assert_eq!(read_file(&path)?, "second");
+        // No temporary files are left behind
+        assert_eq!(std::fs::read_dir(path.parent().unwrap())?.count(), 1);
+        dir.close()
+    }
+
     #[test]
     fn render_time_test_0ms() {
         assert_eq!(render_time(0_u128, true), "0ms");
@@ -964,6 +1065,45 @@ mod tests {
         assert_eq!(get_command_string_output(case2), "stderr");
     }
 
+    #[test]
+    fn fs_type_of_deepest_mount() {
+        let mountinfo = "\
+22 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw
+35 22 0:31 / /home rw,relatime shared:2 - nfs4 server:/home rw,vers=4.2
+36 35 0:32 / /home/user/remote\\040dir rw,nosuid - fuse.sshfs user@host:/ rw
+37 22 0:33 / /home2 rw - xfs /dev/sdb1 rw
+";
+        let fs_type = |path: &str| fs_type_from_mountinfo(mountinfo, Path::new(path));
+        assert_eq!(fs_type("/usr/bin").as_deref(), Some("ext4"));
+        assert_eq!(fs_type("/home/user/src").as_deref(), Some("nfs4"));
+        assert_eq!(
+            fs_type("/home/user/remote dir/src").as_deref(),
+            Some("fuse.sshfs")
+        );
+        assert_eq!(fs_type("/home2").as_deref(), Some("xfs"));
+    }
+
+    #[test]
+    fn fs_type_of_shadowed_mount() {
+        let mountinfo = "\
+22 1 8:1 / / rw - ext4 /dev/sda1 rw
+35 22 0:31 / /mnt rw - nfs server:/old rw
+36 22 0:32 / /mnt rw - cifs //server/new rw
+";
+        let fs_type = fs_type_from_mountinfo(mountinfo, Path::new("/mnt/share"));
+        assert_eq!(fs_type.as_deref(), Some("cifs"));
+    }
+
+    #[test]
+    fn unescape_mountinfo_paths() {
+        assert_eq!(unescape_mountinfo(r"/mnt/a\040b\011c"), "/mnt/a b\tc");
+        assert_eq!(
+            unescape_mountinfo(r"/mnt/back\134slash"),
+            r"/mnt/back\slash"
+        );
+        assert_eq!(unescape_mountinfo(r"/mnt/trailing\"), r"/mnt/trailing\");
+    }
+
     #[test]
     fn sha1_hex() {
         assert_eq!(
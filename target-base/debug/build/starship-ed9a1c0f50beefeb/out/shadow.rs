@@ -0,0 +1,1440 @@
+// Code automatically generated by `shadow-rs` (https://github.com/baoyachi/shadow-rs), do not edit.
+// Author: https://www.github.com/baoyachi
+// Generation time: Mon, 19 Oct 2026 00:13:50 +0000
+
+
+
+#[doc=r#"
+The name of the Git branch that this project was built from.
+This constant will be empty if the branch cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BRANCH :&str = r#""#;
+
+#[doc=r#"
+Operating system and architecture on which the project was build.
+The format of this variable is always `os-arch`,
+where `os` is the operating system name as returned by [`std::env::consts::OS`],
+and `arch` is the computer architecture as returned by [`std::env::consts::ARCH`]."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_OS :&str = r#"linux-x86_64"#;
+
+#[doc=r#"
+The debug configuration with which the project was built.
+Note that this is not the Rust channel, but either `debug` or `release`, depending on whether debug assertions were enabled in the build or not. "#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_RUST_CHANNEL :&str = r#"debug"#;
+
+#[doc=r#"
+The [target](https://doc.rust-lang.org/rustc/targets/index.html) for this build.
+This is possibly distinct from the host target during build, in which case this project build was created via cross-compilation."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TARGET :&str = r#"x86_64-unknown-linux-gnu"#;
+
+#[doc=r#"
+The architecture of the target for this build. This is the "architecture" part of the [`BUILD_TARGET`] constant."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TARGET_ARCH :&str = r#"x86_64"#;
+
+#[doc=r#"
+The project build time, formatted in modified ISO 8601 format (`YYYY-MM-DD HH-MM ±hh-mm` where hh-mm is the offset from UTC)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIME :&str = r#"2026-10-19 00:13:50 +00:00"#;
+
+#[doc=r#"
+The project build time as a Unix timestamp (seconds since Unix epoch)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIMESTAMP :i64 = 1792368830;
+
+#[doc=r#"
+The project build time, formatted according to [RFC 2822](https://datatracker.ietf.org/doc/html/rfc2822#section-3.3) (e.g. HTTP Headers)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIME_2822 :&str = r#"Mon, 19 Oct 2026 00:13:50 +0000"#;
+
+#[doc=r#"
+The project build time, formatted according to [RFC 3339 and ISO 8601](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIME_3339 :&str = r#"2026-10-19T00:13:50Z"#;
+
+#[doc=r#"
+List of top-level crate features that are enabled for the build."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_FEATURES :&str = r#"battery,default,notify,notify_rust,starship_battery"#;
+
+#[doc=r#"
+The directory of the Cargo.toml manifest file of the project during build.
+Note that this variable will contain a full local file system path, and will therefore contain sensitive information and not be reproducible."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_MANIFEST_DIR :&str = r#"/tmp/base"#;
+
+#[doc=r#"
+The dependency tree of the project, as output by `cargo tree`.
+Note that this variable may contain local file system paths for path dependencies, and may therefore contain sensitive information and not be reproducible."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_TREE :&str = r#"
+├── chrono v0.4.45
+│   ├── iana-time-zone v0.1.65
+│   └── num-traits v0.2.19
+│       [build-dependencies]
+│       └── autocfg v1.5.1
+├── clap v4.6.7
+│   ├── clap_builder v4.6.7
+│   │   ├── anstream v1.0.0
+│   │   │   ├── anstyle v1.0.14
+│   │   │   ├── anstyle-parse v1.0.0
+│   │   │   │   └── utf8parse v0.2.2
+│   │   │   ├── anstyle-query v1.1.5
+│   │   │   ├── colorchoice v1.0.5
+│   │   │   ├── is_terminal_polyfill v1.70.2
+│   │   │   └── utf8parse v0.2.2
+│   │   ├── anstyle v1.0.14
+│   │   ├── clap_lex v1.1.1
+│   │   ├── strsim v0.11.1
+│   │   ├── unicase v2.10.0
+│   │   └── unicode-width v0.2.2
+│   └── clap_derive v4.6.7 (proc-macro)
+│       ├── heck v0.5.0
+│       ├── proc-macro2 v1.0.107
+│       │   └── unicode-ident v1.0.27
+│       ├── quote v1.0.47
+│       │   └── proc-macro2 v1.0.107 (*)
+│       └── syn v3.0.9
+│           ├── proc-macro2 v1.0.107 (*)
+│           ├── quote v1.0.47 (*)
+│           └── unicode-ident v1.0.27
+├── clap_complete v4.6.11
+│   └── clap v4.6.7 (*)
+├── clap_complete_nushell v4.6.2
+│   ├── clap v4.6.7 (*)
+│   └── clap_complete v4.6.11 (*)
+├── dirs v6.0.0
+│   └── dirs-sys v0.5.0
+│       ├── libc v0.2.190
+│       └── option-ext v0.2.0
+├── dunce v1.0.5
+├── gix v0.73.0
+│   ├── gix-actor v0.35.6
+│   │   ├── bstr v1.13.1
+│   │   │   ├── memchr v2.8.3
+│   │   │   └── regex-automata v0.4.18
+│   │   │       ├── aho-corasick v1.1.5
+│   │   │       │   └── memchr v2.8.3
+│   │   │       ├── memchr v2.8.3
+│   │   │       └── regex-syntax v0.8.11
+│   │   ├── gix-date v0.10.7
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── itoa v1.0.18
+│   │   │   ├── jiff v0.2.38
+│   │   │   │   └── jiff-core v0.1.1
+│   │   │   ├── smallvec v1.16.3
+│   │   │   └── thiserror v2.0.21
+│   │   │       └── thiserror-impl v2.0.21 (proc-macro)
+│   │   │           ├── proc-macro2 v1.0.107 (*)
+│   │   │           ├── quote v1.0.47 (*)
+│   │   │           └── syn v3.0.9 (*)
+│   │   ├── gix-utils v0.3.6
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── fastrand v2.5.0
+│   │   │   ├── getrandom v0.4.3
+│   │   │   │   ├── cfg-if v1.0.5
+│   │   │   │   └── libc v0.2.190
+│   │   │   └── unicode-normalization v0.1.25
+│   │   │       └── tinyvec v1.13.3
+│   │   ├── itoa v1.0.18
+│   │   ├── thiserror v2.0.21 (*)
+│   │   └── winnow v0.7.15
+│   │       └── memchr v2.8.3
+│   ├── gix-attributes v0.27.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-glob v0.21.0
+│   │   │   ├── bitflags v2.13.2
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-features v0.43.1
+│   │   │   │   ├── crc32fast v1.5.2
+│   │   │   │   │   └── cfg-if v1.0.5
+│   │   │   │   ├── crossbeam-channel v0.5.17
+│   │   │   │   │   └── crossbeam-utils v0.8.23
+│   │   │   │   ├── flate2 v1.1.10
+│   │   │   │   │   └── zlib-rs v0.6.8
+│   │   │   │   ├── gix-path v0.10.22
+│   │   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   │   ├── gix-trace v0.1.21
+│   │   │   │   │   ├── gix-validate v0.10.1
+│   │   │   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   │   ├── gix-trace v0.1.21
+│   │   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   │   ├── libc v0.2.190
+│   │   │   │   ├── once_cell v1.21.4
+│   │   │   │   ├── parking_lot v0.12.5
+│   │   │   │   │   ├── lock_api v0.4.14
+│   │   │   │   │   │   └── scopeguard v1.2.0
+│   │   │   │   │   └── parking_lot_core v0.9.12
+│   │   │   │   │       ├── cfg-if v1.0.5
+│   │   │   │   │       ├── libc v0.2.190
+│   │   │   │   │       └── smallvec v1.16.3
+│   │   │   │   ├── prodash v30.0.1
+│   │   │   │   │   └── parking_lot v0.12.5 (*)
+│   │   │   │   ├── thiserror v2.0.21 (*)
+│   │   │   │   └── walkdir v2.5.0
+│   │   │   │       └── same-file v1.0.6
+│   │   │   └── gix-path v0.10.22 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-quote v0.6.2
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-trace v0.1.21
+│   │   ├── kstring v2.0.2
+│   │   │   └── static_assertions v1.1.0
+│   │   ├── smallvec v1.16.3
+│   │   ├── thiserror v2.0.21 (*)
+│   │   └── unicode-bom v2.0.3
+│   ├── gix-command v0.6.5
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-quote v0.6.2 (*)
+│   │   ├── gix-trace v0.1.21
+│   │   └── shell-words v1.1.1
+│   ├── gix-commitgraph v0.29.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-chunk v0.4.12
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-hash v0.19.0
+│   │   │   ├── faster-hex v0.10.1
+│   │   │   │   └── heapless v0.8.0
+│   │   │   │       ├── hash32 v0.3.1
+│   │   │   │       │   └── byteorder v1.5.0
+│   │   │   │       └── stable_deref_trait v1.2.1
+│   │   │   │   [build-dependencies]
+│   │   │   │   └── autocfg v1.5.1
+│   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   ├── sha1-checked v0.10.0
+│   │   │   │   ├── digest v0.10.7
+│   │   │   │   │   ├── block-buffer v0.10.4
+│   │   │   │   │   │   └── generic-array v0.14.7
+│   │   │   │   │   │       └── typenum v1.20.1
+│   │   │   │   │   │       [build-dependencies]
+│   │   │   │   │   │       └── version_check v0.9.5
+│   │   │   │   │   └── crypto-common v0.1.7
+│   │   │   │   │       ├── generic-array v0.14.7 (*)
+│   │   │   │   │       └── typenum v1.20.1
+│   │   │   │   └── sha1 v0.10.7
+│   │   │   │       ├── cfg-if v1.0.5
+│   │   │   │       ├── cpufeatures v0.2.17
+│   │   │   │       └── digest v0.10.7 (*)
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── memmap2 v0.9.11
+│   │   │   └── libc v0.2.190
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-config v0.46.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-config-value v0.15.3
+│   │   │   ├── bitflags v2.13.2
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   ├── libc v0.2.190
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-features v0.43.1 (*)
+│   │   ├── gix-glob v0.21.0 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-ref v0.53.1
+│   │   │   ├── gix-actor v0.35.6 (*)
+│   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   ├── gix-fs v0.16.1
+│   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   ├── fastrand v2.5.0
+│   │   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-lock v18.0.0
+│   │   │   │   ├── gix-tempfile v18.0.0
+│   │   │   │   │   ├── dashmap v6.2.1
+│   │   │   │   │   │   ├── cfg-if v1.0.5
+│   │   │   │   │   │   ├── crossbeam-utils v0.8.23
+│   │   │   │   │   │   ├── hashbrown v0.14.5
+│   │   │   │   │   │   ├── lock_api v0.4.14 (*)
+│   │   │   │   │   │   ├── once_cell v1.21.4
+│   │   │   │   │   │   └── parking_lot_core v0.9.12 (*)
+│   │   │   │   │   ├── gix-fs v0.16.1 (*)
+│   │   │   │   │   ├── libc v0.2.190
+│   │   │   │   │   ├── once_cell v1.21.4
+│   │   │   │   │   ├── parking_lot v0.12.5 (*)
+│   │   │   │   │   └── tempfile v3.27.0
+│   │   │   │   │       ├── fastrand v2.5.0
+│   │   │   │   │       ├── getrandom v0.4.3 (*)
+│   │   │   │   │       ├── once_cell v1.21.4
+│   │   │   │   │       └── rustix v1.1.5
+│   │   │   │   │           ├── bitflags v2.13.2
+│   │   │   │   │           └── linux-raw-sys v0.12.1
+│   │   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   ├── gix-object v0.50.2
+│   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   ├── gix-actor v0.35.6 (*)
+│   │   │   │   ├── gix-date v0.10.7 (*)
+│   │   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   │   ├── gix-hashtable v0.9.0
+│   │   │   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   │   │   ├── hashbrown v0.15.5
+│   │   │   │   │   │   ├── allocator-api2 v0.2.21
+│   │   │   │   │   │   ├── equivalent v1.0.3
+│   │   │   │   │   │   └── foldhash v0.1.5
+│   │   │   │   │   └── parking_lot v0.12.5 (*)
+│   │   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   │   ├── gix-validate v0.10.1 (*)
+│   │   │   │   ├── itoa v1.0.18
+│   │   │   │   ├── smallvec v1.16.3
+│   │   │   │   ├── thiserror v2.0.21 (*)
+│   │   │   │   └── winnow v0.7.15 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   ├── gix-tempfile v18.0.0 (*)
+│   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   ├── gix-validate v0.10.1 (*)
+│   │   │   ├── memmap2 v0.9.11 (*)
+│   │   │   ├── thiserror v2.0.21 (*)
+│   │   │   └── winnow v0.7.15 (*)
+│   │   ├── gix-sec v0.12.2
+│   │   │   ├── bitflags v2.13.2
+│   │   │   └── libc v0.2.190
+│   │   ├── memchr v2.8.3
+│   │   ├── once_cell v1.21.4
+│   │   ├── smallvec v1.16.3
+│   │   ├── thiserror v2.0.21 (*)
+│   │   ├── unicode-bom v2.0.3
+│   │   └── winnow v0.7.15 (*)
+│   ├── gix-date v0.10.7 (*)
+│   ├── gix-diff v0.53.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-attributes v0.27.0 (*)
+│   │   ├── gix-command v0.6.5 (*)
+│   │   ├── gix-filter v0.20.0
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── encoding_rs v0.8.35
+│   │   │   │   └── cfg-if v1.0.5
+│   │   │   ├── gix-attributes v0.27.0 (*)
+│   │   │   ├── gix-command v0.6.5 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   ├── gix-packetline-blocking v0.19.3
+│   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   ├── faster-hex v0.10.1 (*)
+│   │   │   │   ├── gix-trace v0.1.21
+│   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   ├── gix-quote v0.6.2 (*)
+│   │   │   ├── gix-trace v0.1.21
+│   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   ├── smallvec v1.16.3
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-fs v0.16.1 (*)
+│   │   ├── gix-hash v0.19.0 (*)
+│   │   ├── gix-index v0.41.0
+│   │   │   ├── bitflags v2.13.2
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── filetime v0.2.29
+│   │   │   │   ├── cfg-if v1.0.5
+│   │   │   │   └── libc v0.2.190
+│   │   │   ├── fnv v1.0.7
+│   │   │   ├── gix-bitmap v0.2.16
+│   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   ├── gix-fs v0.16.1 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-lock v18.0.0 (*)
+│   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   ├── gix-traverse v0.47.0
+│   │   │   │   ├── bitflags v2.13.2
+│   │   │   │   ├── gix-commitgraph v0.29.0 (*)
+│   │   │   │   ├── gix-date v0.10.7 (*)
+│   │   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   │   ├── gix-hashtable v0.9.0 (*)
+│   │   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   │   ├── gix-revwalk v0.21.0
+│   │   │   │   │   ├── gix-commitgraph v0.29.0 (*)
+│   │   │   │   │   ├── gix-date v0.10.7 (*)
+│   │   │   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   │   │   ├── gix-hashtable v0.9.0 (*)
+│   │   │   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   │   │   ├── smallvec v1.16.3
+│   │   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   │   ├── smallvec v1.16.3
+│   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   ├── gix-utils v0.3.6 (*)
+│   │   │   ├── gix-validate v0.10.1 (*)
+│   │   │   ├── hashbrown v0.15.5 (*)
+│   │   │   ├── itoa v1.0.18
+│   │   │   ├── libc v0.2.190
+│   │   │   ├── memmap2 v0.9.11 (*)
+│   │   │   ├── rustix v1.1.5 (*)
+│   │   │   ├── smallvec v1.16.3
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-object v0.50.2 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-pathspec v0.12.0
+│   │   │   ├── bitflags v2.13.2
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-attributes v0.27.0 (*)
+│   │   │   ├── gix-config-value v0.15.3 (*)
+│   │   │   ├── gix-glob v0.21.0 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-tempfile v18.0.0 (*)
+│   │   ├── gix-trace v0.1.21
+│   │   ├── gix-traverse v0.47.0 (*)
+│   │   ├── gix-worktree v0.42.0
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-attributes v0.27.0 (*)
+│   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   ├── gix-fs v0.16.1 (*)
+│   │   │   ├── gix-glob v0.21.0 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-ignore v0.16.0
+│   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   ├── gix-glob v0.21.0 (*)
+│   │   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   │   ├── gix-trace v0.1.21
+│   │   │   │   └── unicode-bom v2.0.3
+│   │   │   ├── gix-index v0.41.0 (*)
+│   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   └── gix-validate v0.10.1 (*)
+│   │   ├── imara-diff v0.1.8
+│   │   │   └── hashbrown v0.15.5 (*)
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-dir v0.15.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-discover v0.41.0
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-fs v0.16.1 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   ├── gix-ref v0.53.1 (*)
+│   │   │   ├── gix-sec v0.12.2 (*)
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-fs v0.16.1 (*)
+│   │   ├── gix-ignore v0.16.0 (*)
+│   │   ├── gix-index v0.41.0 (*)
+│   │   ├── gix-object v0.50.2 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-pathspec v0.12.0 (*)
+│   │   ├── gix-trace v0.1.21
+│   │   ├── gix-utils v0.3.6 (*)
+│   │   ├── gix-worktree v0.42.0 (*)
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-discover v0.41.0 (*)
+│   ├── gix-features v0.43.1 (*)
+│   ├── gix-filter v0.20.0 (*)
+│   ├── gix-fs v0.16.1 (*)
+│   ├── gix-glob v0.21.0 (*)
+│   ├── gix-hash v0.19.0 (*)
+│   ├── gix-hashtable v0.9.0 (*)
+│   ├── gix-ignore v0.16.0 (*)
+│   ├── gix-index v0.41.0 (*)
+│   ├── gix-lock v18.0.0 (*)
+│   ├── gix-object v0.50.2 (*)
+│   ├── gix-odb v0.70.0
+│   │   ├── arc-swap v1.9.2
+│   │   │   └── rustversion v1.0.23 (proc-macro)
+│   │   ├── gix-date v0.10.7 (*)
+│   │   ├── gix-features v0.43.1 (*)
+│   │   ├── gix-fs v0.16.1 (*)
+│   │   ├── gix-hash v0.19.0 (*)
+│   │   ├── gix-hashtable v0.9.0 (*)
+│   │   ├── gix-object v0.50.2 (*)
+│   │   ├── gix-pack v0.60.0
+│   │   │   ├── clru v0.6.3
+│   │   │   │   └── hashbrown v0.16.1
+│   │   │   │       ├── allocator-api2 v0.2.21
+│   │   │   │       ├── equivalent v1.0.3
+│   │   │   │       └── foldhash v0.2.0
+│   │   │   ├── gix-chunk v0.4.12 (*)
+│   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-hashtable v0.9.0 (*)
+│   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   ├── memmap2 v0.9.11 (*)
+│   │   │   ├── smallvec v1.16.3
+│   │   │   ├── thiserror v2.0.21 (*)
+│   │   │   └── uluru v3.1.0
+│   │   │       └── arrayvec v0.7.8
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-quote v0.6.2 (*)
+│   │   ├── parking_lot v0.12.5 (*)
+│   │   ├── tempfile v3.27.0 (*)
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-pack v0.60.0 (*)
+│   ├── gix-path v0.10.22 (*)
+│   ├── gix-pathspec v0.12.0 (*)
+│   ├── gix-protocol v0.51.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-date v0.10.7 (*)
+│   │   ├── gix-features v0.43.1 (*)
+│   │   ├── gix-hash v0.19.0 (*)
+│   │   ├── gix-ref v0.53.1 (*)
+│   │   ├── gix-shallow v0.5.0
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-lock v18.0.0 (*)
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-transport v0.48.0
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-command v0.6.5 (*)
+│   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   ├── gix-packetline v0.19.3
+│   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   ├── faster-hex v0.10.1 (*)
+│   │   │   │   ├── gix-trace v0.1.21
+│   │   │   │   └── thiserror v2.0.21 (*)
+│   │   │   ├── gix-quote v0.6.2 (*)
+│   │   │   ├── gix-sec v0.12.2 (*)
+│   │   │   ├── gix-url v0.32.0
+│   │   │   │   ├── bstr v1.13.1 (*)
+│   │   │   │   ├── gix-features v0.43.1 (*)
+│   │   │   │   ├── gix-path v0.10.22 (*)
+│   │   │   │   ├── percent-encoding v2.3.2
+│   │   │   │   ├── thiserror v2.0.21 (*)
+│   │   │   │   └── url v2.5.8
+│   │   │   │       ├── form_urlencoded v1.2.2
+│   │   │   │       │   └── percent-encoding v2.3.2
+│   │   │   │       ├── idna v1.1.0
+│   │   │   │       │   ├── idna_adapter v1.2.1
+│   │   │   │       │   │   ├── icu_normalizer v2.1.1
+│   │   │   │       │   │   │   ├── icu_collections v2.1.1
+│   │   │   │       │   │   │   │   ├── displaydoc v0.2.7 (proc-macro)
+│   │   │   │       │   │   │   │   │   ├── proc-macro2 v1.0.107 (*)
+│   │   │   │       │   │   │   │   │   ├── quote v1.0.47 (*)
+│   │   │   │       │   │   │   │   │   └── syn v3.0.9 (*)
+│   │   │   │       │   │   │   │   ├── potential_utf v0.1.6
+│   │   │   │       │   │   │   │   │   └── zerovec v0.11.8
+│   │   │   │       │   │   │   │   │       ├── yoke v0.8.3
+│   │   │   │       │   │   │   │   │       │   ├── stable_deref_trait v1.2.1
+│   │   │   │       │   │   │   │   │       │   ├── yoke-derive v0.8.4 (proc-macro)
+│   │   │   │       │   │   │   │   │       │   │   ├── proc-macro2 v1.0.107 (*)
+│   │   │   │       │   │   │   │   │       │   │   ├── quote v1.0.47 (*)
+│   │   │   │       │   │   │   │   │       │   │   ├── syn v3.0.9 (*)
+│   │   │   │       │   │   │   │   │       │   │   └── synstructure v0.14.0
+│   │   │   │       │   │   │   │   │       │   │       ├── proc-macro2 v1.0.107 (*)
+│   │   │   │       │   │   │   │   │       │   │       ├── quote v1.0.47 (*)
+│   │   │   │       │   │   │   │   │       │   │       └── syn v3.0.9 (*)
+│   │   │   │       │   │   │   │   │       │   └── zerofrom v0.1.8
+│   │   │   │       │   │   │   │   │       │       └── zerofrom-derive v0.1.8 (proc-macro)
+│   │   │   │       │   │   │   │   │       │           ├── proc-macro2 v1.0.107 (*)
+│   │   │   │       │   │   │   │   │       │           ├── quote v1.0.47 (*)
+│   │   │   │       │   │   │   │   │       │           ├── syn v3.0.9 (*)
+│   │   │   │       │   │   │   │   │       │           └── synstructure v0.14.0 (*)
+│   │   │   │       │   │   │   │   │       ├── zerofrom v0.1.8 (*)
+│   │   │   │       │   │   │   │   │       └── zerovec-derive v0.11.6 (proc-macro)
+│   │   │   │       │   │   │   │   │           ├── proc-macro2 v1.0.107 (*)
+│   │   │   │       │   │   │   │   │           ├── quote v1.0.47 (*)
+│   │   │   │       │   │   │   │   │           └── syn v3.0.9 (*)
+│   │   │   │       │   │   │   │   ├── yoke v0.8.3 (*)
+│   │   │   │       │   │   │   │   ├── zerofrom v0.1.8 (*)
+│   │   │   │       │   │   │   │   └── zerovec v0.11.8 (*)
+│   │   │   │       │   │   │   ├── icu_normalizer_data v2.1.1
+│   │   │   │       │   │   │   ├── icu_provider v2.1.1
+│   │   │   │       │   │   │   │   ├── displaydoc v0.2.7 (proc-macro) (*)
+│   │   │   │       │   │   │   │   ├── icu_locale_core v2.1.1
+│   │   │   │       │   │   │   │   │   ├── displaydoc v0.2.7 (proc-macro) (*)
+│   │   │   │       │   │   │   │   │   ├── litemap v0.8.3
+│   │   │   │       │   │   │   │   │   ├── tinystr v0.8.4
+│   │   │   │       │   │   │   │   │   │   ├── displaydoc v0.2.7 (proc-macro) (*)
+│   │   │   │       │   │   │   │   │   │   └── zerovec v0.11.8 (*)
+│   │   │   │       │   │   │   │   │   ├── writeable v0.6.4
+│   │   │   │       │   │   │   │   │   └── zerovec v0.11.8 (*)
+│   │   │   │       │   │   │   │   ├── writeable v0.6.4
+│   │   │   │       │   │   │   │   ├── yoke v0.8.3 (*)
+│   │   │   │       │   │   │   │   ├── zerofrom v0.1.8 (*)
+│   │   │   │       │   │   │   │   ├── zerotrie v0.2.5
+│   │   │   │       │   │   │   │   │   ├── displaydoc v0.2.7 (proc-macro) (*)
+│   │   │   │       │   │   │   │   │   ├── yoke v0.8.3 (*)
+│   │   │   │       │   │   │   │   │   └── zerofrom v0.1.8 (*)
+│   │   │   │       │   │   │   │   └── zerovec v0.11.8 (*)
+│   │   │   │       │   │   │   ├── smallvec v1.16.3
+│   │   │   │       │   │   │   └── zerovec v0.11.8 (*)
+│   │   │   │       │   │   └── icu_properties v2.1.2
+│   │   │   │       │   │       ├── icu_collections v2.1.1 (*)
+│   │   │   │       │   │       ├── icu_locale_core v2.1.1 (*)
+│   │   │   │       │   │       ├── icu_properties_data v2.1.2
+│   │   │   │       │   │       ├── icu_provider v2.1.1 (*)
+│   │   │   │       │   │       ├── zerotrie v0.2.5 (*)
+│   │   │   │       │   │       └── zerovec v0.11.8 (*)
+│   │   │   │       │   ├── smallvec v1.16.3
+│   │   │   │       │   └── utf8_iter v1.0.4
+│   │   │   │       └── percent-encoding v2.3.2
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-utils v0.3.6 (*)
+│   │   ├── maybe-async v0.2.11 (proc-macro)
+│   │   │   ├── proc-macro2 v1.0.107 (*)
+│   │   │   ├── quote v1.0.47 (*)
+│   │   │   └── syn v2.0.119
+│   │   │       ├── proc-macro2 v1.0.107 (*)
+│   │   │       ├── quote v1.0.47 (*)
+│   │   │       └── unicode-ident v1.0.27
+│   │   ├── thiserror v2.0.21 (*)
+│   │   └── winnow v0.7.15 (*)
+│   ├── gix-ref v0.53.1 (*)
+│   ├── gix-refspec v0.31.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-hash v0.19.0 (*)
+│   │   ├── gix-revision v0.35.0
+│   │   │   ├── bitflags v2.13.2
+│   │   │   ├── bstr v1.13.1 (*)
+│   │   │   ├── gix-commitgraph v0.29.0 (*)
+│   │   │   ├── gix-date v0.10.7 (*)
+│   │   │   ├── gix-hash v0.19.0 (*)
+│   │   │   ├── gix-hashtable v0.9.0 (*)
+│   │   │   ├── gix-object v0.50.2 (*)
+│   │   │   ├── gix-revwalk v0.21.0 (*)
+│   │   │   ├── gix-trace v0.1.21
+│   │   │   └── thiserror v2.0.21 (*)
+│   │   ├── gix-validate v0.10.1 (*)
+│   │   ├── smallvec v1.16.3
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-revision v0.35.0 (*)
+│   ├── gix-revwalk v0.21.0 (*)
+│   ├── gix-sec v0.12.2 (*)
+│   ├── gix-shallow v0.5.0 (*)
+│   ├── gix-status v0.20.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── filetime v0.2.29 (*)
+│   │   ├── gix-diff v0.53.0 (*)
+│   │   ├── gix-dir v0.15.0 (*)
+│   │   ├── gix-features v0.43.1 (*)
+│   │   ├── gix-filter v0.20.0 (*)
+│   │   ├── gix-fs v0.16.1 (*)
+│   │   ├── gix-hash v0.19.0 (*)
+│   │   ├── gix-index v0.41.0 (*)
+│   │   ├── gix-object v0.50.2 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-pathspec v0.12.0 (*)
+│   │   ├── gix-worktree v0.42.0 (*)
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-submodule v0.20.0
+│   │   ├── bstr v1.13.1 (*)
+│   │   ├── gix-config v0.46.0 (*)
+│   │   ├── gix-path v0.10.22 (*)
+│   │   ├── gix-pathspec v0.12.0 (*)
+│   │   ├── gix-refspec v0.31.0 (*)
+│   │   ├── gix-url v0.32.0 (*)
+│   │   └── thiserror v2.0.21 (*)
+│   ├── gix-tempfile v18.0.0 (*)
+│   ├── gix-trace v0.1.21
+│   ├── gix-traverse v0.47.0 (*)
+│   ├── gix-url v0.32.0 (*)
+│   ├── gix-utils v0.3.6 (*)
+│   ├── gix-validate v0.10.1 (*)
+│   ├── gix-worktree v0.42.0 (*)
+│   ├── once_cell v1.21.4
+│   ├── smallvec v1.16.3
+│   └── thiserror v2.0.21 (*)
+├── guess_host_triple v0.1.5
+│   ├── errno v0.2.8
+│   │   └── libc v0.2.190
+│   ├── libc v0.2.190
+│   └── log v0.4.34
+├── home v0.5.11
+├── indexmap v2.14.2
+│   ├── equivalent v1.0.3
+│   ├── hashbrown v0.17.1
+│   └── serde_core v1.0.229
+├── jsonc-parser v0.26.3
+│   └── serde_json v1.0.154
+│       ├── itoa v1.0.18
+│       ├── memchr v2.8.3
+│       ├── serde_core v1.0.229
+│       └── zmij v1.0.23
+├── log v0.4.34
+├── nix v0.30.1
+│   ├── bitflags v2.13.2
+│   ├── cfg-if v1.0.5
+│   └── libc v0.2.190
+│   [build-dependencies]
+│   └── cfg_aliases v0.2.2
+├── notify-rust v4.17.0
+│   ├── futures-lite v2.6.1
+│   │   ├── fastrand v2.5.0
+│   │   ├── futures-core v0.3.34
+│   │   ├── futures-io v0.3.34
+│   │   ├── parking v2.2.1
+│   │   └── pin-project-lite v0.2.17
+│   ├── log v0.4.34
+│   ├── serde v1.0.229
+│   │   ├── serde_core v1.0.229
+│   │   └── serde_derive v1.0.229 (proc-macro)
+│   │       ├── proc-macro2 v1.0.107 (*)
+│   │       ├── quote v1.0.47 (*)
+│   │       └── syn v3.0.9 (*)
+│   └── zbus v5.13.2
+│       ├── async-broadcast v0.7.2
+│       │   ├── event-listener v5.4.2
+│       │   │   ├── parking v2.2.1
+│       │   │   └── pin-project-lite v0.2.17
+│       │   ├── event-listener-strategy v0.5.4
+│       │   │   ├── event-listener v5.4.2 (*)
+│       │   │   └── pin-project-lite v0.2.17
+│       │   ├── futures-core v0.3.34
+│       │   └── pin-project-lite v0.2.17
+│       ├── async-executor v1.14.0
+│       │   ├── async-task v4.7.1
+│       │   ├── concurrent-queue v2.5.0
+│       │   │   └── crossbeam-utils v0.8.23
+│       │   ├── fastrand v2.5.0
+│       │   ├── futures-lite v2.6.1 (*)
+│       │   ├── pin-project-lite v0.2.17
+│       │   └── slab v0.4.12
+│       ├── async-io v2.6.0
+│       │   ├── cfg-if v1.0.5
+│       │   ├── concurrent-queue v2.5.0 (*)
+│       │   ├── futures-io v0.3.34
+│       │   ├── futures-lite v2.6.1 (*)
+│       │   ├── parking v2.2.1
+│       │   ├── polling v3.11.0
+│       │   │   ├── cfg-if v1.0.5
+│       │   │   └── rustix v1.1.5 (*)
+│       │   ├── rustix v1.1.5 (*)
+│       │   └── slab v0.4.12
+│       │   [build-dependencies]
+│       │   └── autocfg v1.5.1
+│       ├── async-lock v3.4.2
+│       │   ├── event-listener v5.4.2 (*)
+│       │   ├── event-listener-strategy v0.5.4 (*)
+│       │   └── pin-project-lite v0.2.17
+│       ├── async-process v2.5.0
+│       │   ├── async-channel v2.5.0
+│       │   │   ├── concurrent-queue v2.5.0 (*)
+│       │   │   ├── event-listener-strategy v0.5.4 (*)
+│       │   │   ├── futures-core v0.3.34
+│       │   │   └── pin-project-lite v0.2.17
+│       │   ├── async-io v2.6.0 (*)
+│       │   ├── async-lock v3.4.2 (*)
+│       │   ├── async-signal v0.2.14
+│       │   │   ├── async-io v2.6.0 (*)
+│       │   │   ├── cfg-if v1.0.5
+│       │   │   ├── futures-core v0.3.34
+│       │   │   ├── futures-io v0.3.34
+│       │   │   ├── rustix v1.1.5 (*)
+│       │   │   └── signal-hook-registry v1.4.8
+│       │   │       ├── errno v0.3.14
+│       │   │       │   └── libc v0.2.190
+│       │   │       └── libc v0.2.190
+│       │   ├── async-task v4.7.1
+│       │   ├── cfg-if v1.0.5
+│       │   ├── event-listener v5.4.2 (*)
+│       │   ├── futures-lite v2.6.1 (*)
+│       │   └── rustix v1.1.5 (*)
+│       ├── async-task v4.7.1
+│       ├── async-trait v0.1.92 (proc-macro)
+│       │   ├── proc-macro2 v1.0.107 (*)
+│       │   ├── quote v1.0.47 (*)
+│       │   └── syn v3.0.9 (*)
+│       ├── blocking v1.7.0
+│       │   ├── async-channel v2.5.0 (*)
+│       │   ├── async-task v4.7.1
+│       │   ├── futures-io v0.3.34
+│       │   ├── futures-lite v2.6.1 (*)
+│       │   └── piper v0.2.5
+│       │       ├── atomic-waker v1.1.2
+│       │       ├── fastrand v2.5.0
+│       │       └── futures-io v0.3.34
+│       ├── enumflags2 v0.7.12
+│       │   ├── enumflags2_derive v0.7.12 (proc-macro)
+│       │   │   ├── proc-macro2 v1.0.107 (*)
+│       │   │   ├── quote v1.0.47 (*)
+│       │   │   └── syn v2.0.119 (*)
+│       │   └── serde v1.0.229 (*)
+│       ├── event-listener v5.4.2 (*)
+│       ├── futures-core v0.3.34
+│       ├── futures-lite v2.6.1 (*)
+│       ├── hex v0.4.3
+│       ├── libc v0.2.190
+│       ├── ordered-stream v0.2.0
+│       │   ├── futures-core v0.3.34
+│       │   └── pin-project-lite v0.2.17
+│       ├── rustix v1.1.5 (*)
+│       ├── serde v1.0.229 (*)
+│       ├── serde_repr v0.1.21 (proc-macro)
+│       │   ├── proc-macro2 v1.0.107 (*)
+│       │   ├── quote v1.0.47 (*)
+│       │   └── syn v3.0.9 (*)
+│       ├── tracing v0.1.44
+│       │   ├── pin-project-lite v0.2.17
+│       │   ├── tracing-attributes v0.1.31 (proc-macro)
+│       │   │   ├── proc-macro2 v1.0.107 (*)
+│       │   │   ├── quote v1.0.47 (*)
+│       │   │   └── syn v2.0.119 (*)
+│       │   └── tracing-core v0.1.36
+│       │       └── once_cell v1.21.4
+│       ├── uuid v1.26.1
+│       │   └── serde_core v1.0.229
+│       ├── winnow v0.7.15 (*)
+│       ├── zbus_macros v5.13.2 (proc-macro)
+│       │   ├── proc-macro-crate v3.5.0
+│       │   │   └── toml_edit v0.25.17+spec-1.1.0
+│       │   │       ├── indexmap v2.14.2
+│       │   │       │   ├── equivalent v1.0.3
+│       │   │       │   └── hashbrown v0.17.1
+│       │   │       ├── toml_datetime v1.1.2+spec-1.1.0
+│       │   │       ├── toml_parser v1.1.5+spec-1.1.0
+│       │   │       │   └── winnow v1.0.4
+│       │   │       └── winnow v1.0.4
+│       │   ├── proc-macro2 v1.0.107 (*)
+│       │   ├── quote v1.0.47 (*)
+│       │   ├── syn v2.0.119 (*)
+│       │   ├── zbus_names v4.3.1
+│       │   │   ├── serde v1.0.229 (*)
+│       │   │   ├── winnow v0.7.15
+│       │   │   └── zvariant v5.9.2
+│       │   │       ├── endi v1.1.1
+│       │   │       ├── enumflags2 v0.7.12 (*)
+│       │   │       ├── serde v1.0.229 (*)
+│       │   │       ├── winnow v0.7.15
+│       │   │       ├── zvariant_derive v5.9.2 (proc-macro)
+│       │   │       │   ├── proc-macro-crate v3.5.0 (*)
+│       │   │       │   ├── proc-macro2 v1.0.107 (*)
+│       │   │       │   ├── quote v1.0.47 (*)
+│       │   │       │   ├── syn v2.0.119 (*)
+│       │   │       │   └── zvariant_utils v3.3.0
+│       │   │       │       ├── proc-macro2 v1.0.107 (*)
+│       │   │       │       ├── quote v1.0.47 (*)
+│       │   │       │       ├── serde v1.0.229 (*)
+│       │   │       │       ├── syn v2.0.119 (*)
+│       │   │       │       └── winnow v0.7.15
+│       │   │       └── zvariant_utils v3.3.0 (*)
+│       │   ├── zvariant v5.9.2 (*)
+│       │   └── zvariant_utils v3.3.0 (*)
+│       ├── zbus_names v4.3.1 (*)
+│       └── zvariant v5.9.2 (*)
+├── nu-ansi-term v0.50.3
+├── open v5.4.4
+│   ├── is-wsl v0.4.0
+│   │   ├── is-docker v0.2.0
+│   │   │   └── once_cell v1.21.4
+│   │   └── once_cell v1.21.4
+│   └── libc v0.2.190
+├── os_info v3.15.0
+│   ├── log v0.4.34
+│   ├── nix v0.31.3
+│   │   ├── bitflags v2.13.2
+│   │   ├── cfg-if v1.0.5
+│   │   └── libc v0.2.190
+│   │   [build-dependencies]
+│   │   └── cfg_aliases v0.2.2
+│   └── serde v1.0.229 (*)
+├── parking_lot v0.12.5 (*)
+├── path-slash v0.2.1
+├── pest v2.9.3
+│   ├── memchr v2.8.3
+│   ├── psm v0.1.24
+│   │   [build-dependencies]
+│   │   └── cc v1.8.0
+│   │       ├── find-msvc-tools v0.1.14
+│   │       └── shlex v2.0.1
+│   ├── stacker v0.1.25
+│   │   ├── cfg-if v1.0.5
+│   │   ├── libc v0.2.190
+│   │   └── psm v0.1.24 (*)
+│   │   [build-dependencies]
+│   │   └── cc v1.8.0 (*)
+│   └── ucd-trie v0.1.7
+├── pest_derive v2.9.3 (proc-macro)
+│   ├── pest v2.9.3 (*)
+│   └── pest_generator v2.9.3
+│       ├── pest v2.9.3 (*)
+│       ├── pest_meta v2.9.3
+│       │   └── pest v2.9.3 (*)
+│       ├── proc-macro2 v1.0.107 (*)
+│       ├── quote v1.0.47 (*)
+│       └── syn v3.0.9 (*)
+├── process_control v5.2.0
+│   ├── attr_alias v0.1.5 (proc-macro)
+│   └── libc v0.2.190
+├── quick-xml v0.37.5
+│   └── memchr v2.8.3
+├── rand v0.9.5
+│   ├── rand_chacha v0.9.0
+│   │   ├── ppv-lite86 v0.2.21
+│   │   │   └── zerocopy v0.8.63
+│   │   └── rand_core v0.9.5
+│   │       └── getrandom v0.3.4
+│   │           ├── cfg-if v1.0.5
+│   │           └── libc v0.2.190
+│   └── rand_core v0.9.5 (*)
+├── rayon v1.12.0
+│   ├── either v1.19.0
+│   └── rayon-core v1.13.0
+│       ├── crossbeam-deque v0.8.8
+│       │   ├── crossbeam-epoch v0.9.21
+│       │   │   └── crossbeam-utils v0.8.23
+│       │   └── crossbeam-utils v0.8.23
+│       └── crossbeam-utils v0.8.23
+├── regex v1.13.1
+│   ├── aho-corasick v1.1.5 (*)
+│   ├── memchr v2.8.3
+│   ├── regex-automata v0.4.18 (*)
+│   └── regex-syntax v0.8.11
+├── rust-ini v0.21.3
+│   ├── cfg-if v1.0.5
+│   └── ordered-multimap v0.7.3
+│       ├── dlv-list v0.5.2
+│       │   └── const-random v0.1.18
+│       │       └── const-random-macro v0.1.16 (proc-macro)
+│       │           ├── getrandom v0.2.17
+│       │           │   ├── cfg-if v1.0.5
+│       │           │   └── libc v0.2.190
+│       │           ├── once_cell v1.21.4
+│       │           └── tiny-keccak v2.0.2
+│       │               └── crunchy v0.2.4
+│       └── hashbrown v0.14.5
+├── semver v1.0.28
+├── serde v1.0.229 (*)
+├── serde_json v1.0.154 (*)
+├── sha1 v0.10.7 (*)
+├── shadow-rs v1.7.1
+│   ├── const_format v0.2.36
+│   │   ├── const_format_proc_macros v0.2.34 (proc-macro)
+│   │   │   ├── proc-macro2 v1.0.107 (*)
+│   │   │   ├── quote v1.0.47 (*)
+│   │   │   └── unicode-xid v0.2.6
+│   │   └── konst v0.2.20
+│   │       └── konst_macro_rules v0.2.19
+│   ├── is_debug v1.1.0
+│   ├── time v0.3.55
+│   │   ├── deranged v0.5.9
+│   │   ├── libc v0.2.190
+│   │   ├── num-conv v0.2.2
+│   │   ├── num_threads v0.1.7
+│   │   ├── powerfmt v0.2.1
+│   │   └── time-core v0.1.9
+│   └── tzdb v0.7.3
+│       ├── iana-time-zone v0.1.65
+│       ├── tz-rs v0.7.3
+│       └── tzdb_data v0.2.5
+│           └── tz-rs v0.7.3
+├── shell-words v1.1.1
+├── starship-battery v0.10.2
+│   ├── cfg-if v1.0.5
+│   ├── lazycell v1.3.0
+│   ├── num-traits v0.2.19 (*)
+│   └── uom v0.37.0
+│       ├── num-traits v0.2.19 (*)
+│       └── typenum v1.20.1
+├── strsim v0.11.1
+├── systemstat v0.2.5
+│   ├── bytesize v1.3.3
+│   ├── lazy_static v1.5.1
+│   ├── libc v0.2.190
+│   ├── nom v7.1.3
+│   │   ├── memchr v2.8.3
+│   │   └── minimal-lexical v0.2.1
+│   └── time v0.3.55 (*)
+├── terminal_size v0.4.4
+│   └── rustix v1.1.5 (*)
+├── toml v0.9.12+spec-1.1.0
+│   ├── indexmap v2.14.2 (*)
+│   ├── serde_core v1.0.229
+│   ├── serde_spanned v1.1.2
+│   │   └── serde_core v1.0.229
+│   ├── toml_datetime v0.7.5+spec-1.1.0
+│   │   └── serde_core v1.0.229
+│   ├── toml_parser v1.1.5+spec-1.1.0 (*)
+│   ├── toml_writer v1.1.3+spec-1.1.0
+│   └── winnow v0.7.15 (*)
+├── toml_edit v0.23.10+spec-1.0.0
+│   ├── indexmap v2.14.2 (*)
+│   ├── toml_datetime v0.7.5+spec-1.1.0 (*)
+│   ├── toml_parser v1.1.5+spec-1.1.0 (*)
+│   ├── toml_writer v1.1.3+spec-1.1.0
+│   └── winnow v0.7.15 (*)
+├── unicode-segmentation v1.13.3
+├── unicode-width v0.2.2
+├── urlencoding v2.1.3
+├── versions v7.0.0
+│   ├── itertools v0.14.0
+│   │   └── either v1.19.0
+│   └── nom v8.0.0
+│       └── memchr v2.8.3
+├── which v8.0.6
+│   └── libc v0.2.190
+├── whoami v1.6.1
+└── yaml-rust2 v0.10.4
+    ├── arraydeque v0.5.1
+    ├── encoding_rs v0.8.35 (*)
+    └── hashlink v0.10.0
+        └── hashbrown v0.15.5 (*)
+[build-dependencies]
+├── dunce v1.0.5
+└── shadow-rs v1.7.1 (*)
+[dev-dependencies]
+├── mockall v0.13.1
+│   ├── cfg-if v1.0.5
+│   ├── downcast v0.11.0
+│   ├── fragile v2.1.0
+│   │   └── futures-core v0.3.34
+│   ├── mockall_derive v0.13.1 (proc-macro)
+│   │   ├── cfg-if v1.0.5
+│   │   ├── proc-macro2 v1.0.107 (*)
+│   │   ├── quote v1.0.47 (*)
+│   │   └── syn v2.0.119 (*)
+│   ├── predicates v3.1.4
+│   │   ├── anstyle v1.0.14
+│   │   └── predicates-core v1.0.10
+│   └── predicates-tree v1.0.13
+│       ├── predicates-core v1.0.10
+│       └── termtree v0.5.1
+└── tempfile v3.27.0 (*)
+"#;
+
+#[doc=r#"
+The cargo version which which the project was built, as output by `cargo --version`."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_VERSION :&str = r#"cargo 1.95.0 (f2d3ce0bd 2026-03-21)"#;
+
+#[doc=r#"
+The number of commits since the last Git tag on the branch that this project was built from.
+This value indicates how many commits have been made after the last tag and before the current commit.
+
+If there are no additional commits after the last tag (i.e., the current commit is exactly at a tag),
+this value will be `0`.
+
+This constant will be empty or `0` if the last tag cannot be determined or if there are no commits after it.
+"#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMITS_SINCE_TAG :usize = 0;
+
+#[doc=r#"
+The author of the Git commit that this project was built from.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_AUTHOR :&str = r#""#;
+
+#[doc=r#"The time of the Git commit that this project was built from.
+The time is formatted in modified ISO 8601 format (`YYYY-MM-DD HH-MM ±hh-mm` where hh-mm is the offset from UTC).
+The timezone information from the original commit is preserved.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_DATE :&str = r#""#;
+
+#[doc=r#"
+The time of the Git commit that this project was built from.
+The time is formatted according to [RFC 2822](https://datatracker.ietf.org/doc/html/rfc2822#section-3.3) (e.g. HTTP Headers).
+The timezone information from the original commit is preserved.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_DATE_2822 :&str = r#""#;
+
+#[doc=r#"
+The time of the Git commit that this project was built from.
+The time is formatted according to [RFC 3339 and ISO 8601](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6).
+The timezone information from the original commit is preserved.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_DATE_3339 :&str = r#""#;
+
+#[doc=r#"
+The e-mail address of the author of the Git commit that this project was built from.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_EMAIL :&str = r#""#;
+
+#[doc=r#"
+The full commit hash of the Git commit that this project was built from.
+An abbreviated, but not necessarily unique, version of this is [`SHORT_COMMIT`].
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_HASH :&str = r#""#;
+
+#[doc=r#"
+The time of the Git commit as a Unix timestamp (seconds since Unix epoch).
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_TIMESTAMP :&str = r#""#;
+
+#[doc=r#"
+Whether the Git working tree was clean at the time of project build (`true`), or not (`false`).
+
+This constant will be `false` if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const GIT_CLEAN :bool = true;
+
+#[doc=r#"
+The Git working tree status as a list of files with their status, similar to `git status`.
+Each line of the list is preceded with `  * `, followed by the file name.
+Files marked `(dirty)` have unstaged changes.
+Files marked `(staged)` have staged changes.
+
+This constant will be empty if the working tree status cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const GIT_STATUS_FILE :&str = r#""#;
+
+#[doc=r#"
+The name of the last Git tag on the branch that this project was built from.
+As opposed to [`TAG`], this does not require the current commit to be tagged, just one of its parents.
+
+This constant will be empty if the last tag cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const LAST_TAG :&str = r#""#;
+
+#[doc=r#"
+The project's description, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_DESCRIPTION :&str = r#"The minimal, blazing-fast, and infinitely customizable prompt for any shell! ☄🌌️
+"#;
+
+#[doc=r#"
+The project's full version string, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION :&str = r#"1.23.0"#;
+
+#[doc=r#"
+The project's semver major version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_MAJOR :&str = r#"1"#;
+
+#[doc=r#"
+The project's semver minor version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_MINOR :&str = r#"23"#;
+
+#[doc=r#"
+The project's semver patch version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_PATCH :&str = r#"0"#;
+
+#[doc=r#"
+The project's semver pre-release version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_PRE :&str = r#""#;
+
+#[doc=r#"
+The project name, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PROJECT_NAME :&str = r#"starship"#;
+
+#[doc=r#"
+The [Rustup toolchain](https://rust-lang.github.io/rustup/concepts/toolchains.html) with which the project was built.
+Note that as per Rustup toolchain format, this variable may or may not contain host and date information,
+but it will always contain [channel](https://rust-lang.github.io/rustup/concepts/channels.html) information (stable, beta or nightly)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const RUST_CHANNEL :&str = r#"stable-x86_64-unknown-linux-gnu"#;
+
+#[doc=r#"
+Rust version with which the project was built.
+The version always uses the canonical Rust version format,
+and is therefore identical to the output of the build toolchain's `rustc --version`."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const RUST_VERSION :&str = r#"rustc 1.95.0 (59807616e 2026-04-14)"#;
+
+#[doc=r#"
+The short hash of the Git commit that this project was built from.
+Note that this will always truncate [`COMMIT_HASH`] to 8 characters if necessary.
+Depending on the amount of commits in your project, this may not yield a unique Git identifier
+([see here for more details on hash abbreviation](https://git-scm.com/docs/git-describe#_examples)).
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const SHORT_COMMIT :&str = r#""#;
+
+#[doc=r#"
+The name of the Git tag that this project was built from.
+Note that this will be empty if there is no tag for the HEAD at the time of build."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const TAG :&str = r#""#;
+
+/// A long version string describing the project.
+/// The version string contains the package version, branch, commit hash, build time, and build environment on separate lines.
+/// This constant is suitable for printing to the user.
+#[allow(dead_code,missing_docs)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const VERSION:&str = shadow_rs::formatcp!(r#"
+pkg_version:{}
+branch:{}
+commit_hash:{}
+build_time:{}
+build_env:{},{}"#,PKG_VERSION, BRANCH, SHORT_COMMIT, BUILD_TIME, RUST_VERSION, RUST_CHANNEL
+);
+
+
+/// A long version string describing the project.
+/// The version string contains the package version, branch, commit hash, build time, and build environment on separate lines.
+/// This constant is intended to be used by clap or other CLI tools as a long version string.
+#[allow(dead_code,missing_docs)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CLAP_LONG_VERSION:&str = shadow_rs::formatcp!(r#"{}
+branch:{}
+commit_hash:{}
+build_time:{}
+build_env:{},{}"#,PKG_VERSION, BRANCH, SHORT_COMMIT, BUILD_TIME, RUST_VERSION, RUST_CHANNEL
+);
+
+
+/// A struct that implements [`core::fmt::Display`] which
+/// writes consts generated by `shadow-rs` to it's formatter
+#[allow(non_snake_case)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub struct BuildInfoDisplay {
+	pub BRANCH: bool,
+	pub BUILD_OS: bool,
+	pub BUILD_RUST_CHANNEL: bool,
+	pub BUILD_TARGET: bool,
+	pub BUILD_TARGET_ARCH: bool,
+	pub BUILD_TIME: bool,
+	pub BUILD_TIMESTAMP: bool,
+	pub BUILD_TIME_2822: bool,
+	pub BUILD_TIME_3339: bool,
+	pub CARGO_FEATURES: bool,
+	pub CARGO_MANIFEST_DIR: bool,
+	pub CARGO_TREE: bool,
+	pub CARGO_VERSION: bool,
+	pub COMMITS_SINCE_TAG: bool,
+	pub COMMIT_AUTHOR: bool,
+	pub COMMIT_DATE: bool,
+	pub COMMIT_DATE_2822: bool,
+	pub COMMIT_DATE_3339: bool,
+	pub COMMIT_EMAIL: bool,
+	pub COMMIT_HASH: bool,
+	pub COMMIT_TIMESTAMP: bool,
+	pub GIT_CLEAN: bool,
+	pub GIT_STATUS_FILE: bool,
+	pub LAST_TAG: bool,
+	pub PKG_DESCRIPTION: bool,
+	pub PKG_VERSION: bool,
+	pub PKG_VERSION_MAJOR: bool,
+	pub PKG_VERSION_MINOR: bool,
+	pub PKG_VERSION_PATCH: bool,
+	pub PKG_VERSION_PRE: bool,
+	pub PROJECT_NAME: bool,
+	pub RUST_CHANNEL: bool,
+	pub RUST_VERSION: bool,
+	pub SHORT_COMMIT: bool,
+	pub TAG: bool,
+	pub VERSION: bool,
+	pub CLAP_LONG_VERSION: bool,
+}
+
+impl Default for BuildInfoDisplay {
+	#[allow(dead_code)]
+	#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+	/// Every constant that `shadow-rs` tracks will be printed
+	/// except for slices (CARGO_METADATA for example)
+	fn default() -> Self {
+		Self {
+			BRANCH: true,
+			BUILD_OS: true,
+			BUILD_RUST_CHANNEL: true,
+			BUILD_TARGET: true,
+			BUILD_TARGET_ARCH: true,
+			BUILD_TIME: true,
+			BUILD_TIMESTAMP: true,
+			BUILD_TIME_2822: true,
+			BUILD_TIME_3339: true,
+			CARGO_FEATURES: true,
+			CARGO_MANIFEST_DIR: true,
+			CARGO_TREE: true,
+			CARGO_VERSION: true,
+			COMMITS_SINCE_TAG: true,
+			COMMIT_AUTHOR: true,
+			COMMIT_DATE: true,
+			COMMIT_DATE_2822: true,
+			COMMIT_DATE_3339: true,
+			COMMIT_EMAIL: true,
+			COMMIT_HASH: true,
+			COMMIT_TIMESTAMP: true,
+			GIT_CLEAN: true,
+			GIT_STATUS_FILE: true,
+			LAST_TAG: true,
+			PKG_DESCRIPTION: true,
+			PKG_VERSION: true,
+			PKG_VERSION_MAJOR: true,
+			PKG_VERSION_MINOR: true,
+			PKG_VERSION_PATCH: true,
+			PKG_VERSION_PRE: true,
+			PROJECT_NAME: true,
+			RUST_CHANNEL: true,
+			RUST_VERSION: true,
+			SHORT_COMMIT: true,
+			TAG: true,
+			VERSION: true,
+			CLAP_LONG_VERSION: true,
+		}
+	}
+}
+
+impl BuildInfoDisplay {
+	#[allow(dead_code)]
+	#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+	/// Every constant that `shadow-rs` tracks will be printed
+	pub fn all() -> Self {
+		Self {
+			BRANCH: true,
+			BUILD_OS: true,
+			BUILD_RUST_CHANNEL: true,
+			BUILD_TARGET: true,
+			BUILD_TARGET_ARCH: true,
+			BUILD_TIME: true,
+			BUILD_TIMESTAMP: true,
+			BUILD_TIME_2822: true,
+			BUILD_TIME_3339: true,
+			CARGO_FEATURES: true,
+			CARGO_MANIFEST_DIR: true,
+			CARGO_TREE: true,
+			CARGO_VERSION: true,
+			COMMITS_SINCE_TAG: true,
+			COMMIT_AUTHOR: true,
+			COMMIT_DATE: true,
+			COMMIT_DATE_2822: true,
+			COMMIT_DATE_3339: true,
+			COMMIT_EMAIL: true,
+			COMMIT_HASH: true,
+			COMMIT_TIMESTAMP: true,
+			GIT_CLEAN: true,
+			GIT_STATUS_FILE: true,
+			LAST_TAG: true,
+			PKG_DESCRIPTION: true,
+			PKG_VERSION: true,
+			PKG_VERSION_MAJOR: true,
+			PKG_VERSION_MINOR: true,
+			PKG_VERSION_PATCH: true,
+			PKG_VERSION_PRE: true,
+			PROJECT_NAME: true,
+			RUST_CHANNEL: true,
+			RUST_VERSION: true,
+			SHORT_COMMIT: true,
+			TAG: true,
+			VERSION: true,
+			CLAP_LONG_VERSION: true,
+		}
+	}
+}
+
+impl core::fmt::Display for BuildInfoDisplay {
+	#[allow(dead_code)]
+	#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		if self.BRANCH { writeln!(f, "BRANCH:{BRANCH}\n")?; }
+		if self.BUILD_OS { writeln!(f, "BUILD_OS:{BUILD_OS}\n")?; }
+		if self.BUILD_RUST_CHANNEL { writeln!(f, "BUILD_RUST_CHANNEL:{BUILD_RUST_CHANNEL}\n")?; }
+		if self.BUILD_TARGET { writeln!(f, "BUILD_TARGET:{BUILD_TARGET}\n")?; }
+		if self.BUILD_TARGET_ARCH { writeln!(f, "BUILD_TARGET_ARCH:{BUILD_TARGET_ARCH}\n")?; }
+		if self.BUILD_TIME { writeln!(f, "BUILD_TIME:{BUILD_TIME}\n")?; }
+		if self.BUILD_TIMESTAMP { writeln!(f, "BUILD_TIMESTAMP:{BUILD_TIMESTAMP}\n")?; }
+		if self.BUILD_TIME_2822 { writeln!(f, "BUILD_TIME_2822:{BUILD_TIME_2822}\n")?; }
+		if self.BUILD_TIME_3339 { writeln!(f, "BUILD_TIME_3339:{BUILD_TIME_3339}\n")?; }
+		if self.CARGO_FEATURES { writeln!(f, "CARGO_FEATURES:{CARGO_FEATURES}\n")?; }
+		if self.CARGO_MANIFEST_DIR { writeln!(f, "CARGO_MANIFEST_DIR:{CARGO_MANIFEST_DIR}\n")?; }
+		if self.CARGO_TREE { writeln!(f, "CARGO_TREE:{CARGO_TREE}\n")?; }
+		if self.CARGO_VERSION { writeln!(f, "CARGO_VERSION:{CARGO_VERSION}\n")?; }
+		if self.COMMITS_SINCE_TAG { writeln!(f, "COMMITS_SINCE_TAG:{COMMITS_SINCE_TAG}\n")?; }
+		if self.COMMIT_AUTHOR { writeln!(f, "COMMIT_AUTHOR:{COMMIT_AUTHOR}\n")?; }
+		if self.COMMIT_DATE { writeln!(f, "COMMIT_DATE:{COMMIT_DATE}\n")?; }
+		if self.COMMIT_DATE_2822 { writeln!(f, "COMMIT_DATE_2822:{COMMIT_DATE_2822}\n")?; }
+		if self.COMMIT_DATE_3339 { writeln!(f, "COMMIT_DATE_3339:{COMMIT_DATE_3339}\n")?; }
+		if self.COMMIT_EMAIL { writeln!(f, "COMMIT_EMAIL:{COMMIT_EMAIL}\n")?; }
+		if self.COMMIT_HASH { writeln!(f, "COMMIT_HASH:{COMMIT_HASH}\n")?; }
+		if self.COMMIT_TIMESTAMP { writeln!(f, "COMMIT_TIMESTAMP:{COMMIT_TIMESTAMP}\n")?; }
+		if self.GIT_CLEAN { writeln!(f, "GIT_CLEAN:{GIT_CLEAN}\n")?; }
+		if self.GIT_STATUS_FILE { writeln!(f, "GIT_STATUS_FILE:{GIT_STATUS_FILE}\n")?; }
+		if self.LAST_TAG { writeln!(f, "LAST_TAG:{LAST_TAG}\n")?; }
+		if self.PKG_DESCRIPTION { writeln!(f, "PKG_DESCRIPTION:{PKG_DESCRIPTION}\n")?; }
+		if self.PKG_VERSION { writeln!(f, "PKG_VERSION:{PKG_VERSION}\n")?; }
+		if self.PKG_VERSION_MAJOR { writeln!(f, "PKG_VERSION_MAJOR:{PKG_VERSION_MAJOR}\n")?; }
+		if self.PKG_VERSION_MINOR { writeln!(f, "PKG_VERSION_MINOR:{PKG_VERSION_MINOR}\n")?; }
+		if self.PKG_VERSION_PATCH { writeln!(f, "PKG_VERSION_PATCH:{PKG_VERSION_PATCH}\n")?; }
+		if self.PKG_VERSION_PRE { writeln!(f, "PKG_VERSION_PRE:{PKG_VERSION_PRE}\n")?; }
+		if self.PROJECT_NAME { writeln!(f, "PROJECT_NAME:{PROJECT_NAME}\n")?; }
+		if self.RUST_CHANNEL { writeln!(f, "RUST_CHANNEL:{RUST_CHANNEL}\n")?; }
+		if self.RUST_VERSION { writeln!(f, "RUST_VERSION:{RUST_VERSION}\n")?; }
+		if self.SHORT_COMMIT { writeln!(f, "SHORT_COMMIT:{SHORT_COMMIT}\n")?; }
+		if self.TAG { writeln!(f, "TAG:{TAG}\n")?; }
+		if self.VERSION { writeln!(f, "VERSION:{VERSION}\n")?; }
+		if self.CLAP_LONG_VERSION { writeln!(f, "CLAP_LONG_VERSION:{CLAP_LONG_VERSION}\n")?; }
+		Ok(())
+	}
+}
+
+/// Prints all built-in `shadow-rs` build constants
+/// (except for slices) to standard output.
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub fn print_build_in() {
+	println!("{}", BuildInfoDisplay::default());
+}
+
+
+// Below code generated by project custom from by build.rs
+
+
+use crate::print;
+
+pub fn get_preset_list<'a>() -> &'a [print::Preset] {
+    &[
+        print::Preset("bracketed-segments"),
+print::Preset("catppuccin-powerline"),
+print::Preset("gruvbox-rainbow"),
+print::Preset("jetpack"),
+print::Preset("nerd-font-symbols"),
+print::Preset("no-empty-icons"),
+print::Preset("no-nerd-font"),
+print::Preset("no-runtime-versions"),
+print::Preset("pastel-powerline"),
+print::Preset("plain-text-symbols"),
+print::Preset("pure-preset"),
+print::Preset("tokyo-night"),
+
+    ]
+}
+
+pub fn get_preset_content(name: &str) -> &[u8] {
+    match name {
+    "bracketed-segments" => include_bytes!(r"/tmp/base/docs/public/presets/toml/bracketed-segments.toml"),"catppuccin-powerline" => include_bytes!(r"/tmp/base/docs/public/presets/toml/catppuccin-powerline.toml"),"gruvbox-rainbow" => include_bytes!(r"/tmp/base/docs/public/presets/toml/gruvbox-rainbow.toml"),"jetpack" => include_bytes!(r"/tmp/base/docs/public/presets/toml/jetpack.toml"),"nerd-font-symbols" => include_bytes!(r"/tmp/base/docs/public/presets/toml/nerd-font-symbols.toml"),"no-empty-icons" => include_bytes!(r"/tmp/base/docs/public/presets/toml/no-empty-icons.toml"),"no-nerd-font" => include_bytes!(r"/tmp/base/docs/public/presets/toml/no-nerd-font.toml"),"no-runtime-versions" => include_bytes!(r"/tmp/base/docs/public/presets/toml/no-runtime-versions.toml"),"pastel-powerline" => include_bytes!(r"/tmp/base/docs/public/presets/toml/pastel-powerline.toml"),"plain-text-symbols" => include_bytes!(r"/tmp/base/docs/public/presets/toml/plain-text-symbols.toml"),"pure-preset" => include_bytes!(r"/tmp/base/docs/public/presets/toml/pure-preset.toml"),"tokyo-night" => include_bytes!(r"/tmp/base/docs/public/presets/toml/tokyo-night.toml"),
+    _ => unreachable!(),
+    }
+}
+